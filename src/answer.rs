@@ -0,0 +1,71 @@
+use crate::grid::Grid;
+use std::fmt;
+
+/// The result of solving one part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    /// Rows of a picture, for puzzles whose answer is drawn rather than
+    /// computed.
+    Grid(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => {
+                for row in rows {
+                    write!(f, "\n{}", row)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! int_answer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Int(n as i64)
+            }
+        })*
+    };
+}
+
+int_answer!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl From<Grid<char>> for Answer {
+    fn from(grid: Grid<char>) -> Answer {
+        Answer::Grid(grid.rows().map(|row| row.iter().collect()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::grid::Grid;
+
+    #[test]
+    fn display() {
+        assert_eq!("-12", Answer::from(-12).to_string());
+        assert_eq!("abc", Answer::from("abc").to_string());
+        let grid = Grid::new(vec![vec!['#', '.'], vec!['.', '#']]);
+        assert_eq!("\n#.\n.#", Answer::from(grid).to_string());
+    }
+}
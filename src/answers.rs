@@ -0,0 +1,218 @@
+use crate::answer::Answer;
+use std::fmt;
+use std::path::Path;
+
+/// Recorded answers, one `<day>\t<part>\t<answer>` line per entry. Newlines
+/// in an answer are written as `\n` and backslashes as `\\`; lines starting
+/// with `#` are comments.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: Vec<(String, u8, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AnswersError {
+    pub line: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// The text an answer is recorded and compared as.
+pub fn text(answer: &Answer) -> String {
+    match answer {
+        Answer::Grid(rows) => rows.join("\n"),
+        _ => answer.to_string(),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut res = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                res.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                res.push('\\');
+                chars.next();
+            }
+            (c, _) => res.push(c),
+        }
+    }
+    res
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, AnswersError> {
+        let mut answers = Answers::default();
+        for (i, line) in text.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = |message| AnswersError {
+                line: i + 1,
+                message,
+            };
+            let mut fields = line.splitn(3, '\t');
+            let day = fields.next().filter(|s| !s.is_empty());
+            let part = fields.next().map(|s| s.parse::<u8>());
+            match (day, part, fields.next()) {
+                (Some(day), Some(Ok(part)), Some(answer)) if part == 1 || part == 2 => {
+                    answers.set(day, part, unescape(answer))
+                }
+                (Some(_), Some(_), Some(_)) => return Err(err("part must be 1 or 2")),
+                _ => return Err(err("expected <day>\\t<part>\\t<answer>")),
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Loads `path`, or returns no answers if it does not exist.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|e| format!("{}:{}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("cannot read `{}`: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: &str, part: u8) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(d, p, _)| d == day && *p == part)
+            .map(|(_, _, answer)| answer.as_str())
+    }
+
+    pub fn set(&mut self, day: &str, part: u8, answer: String) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|(d, p, _)| d == day && *p == part)
+        {
+            Some(entry) => entry.2 = answer,
+            None => self.entries.push((day.to_string(), part, answer)),
+        }
+    }
+
+    /// Orders the entries by `key` of their day, then by part.
+    pub fn sort_by_day<K: Ord, F: Fn(&str) -> K>(&mut self, key: F) {
+        self.entries.sort_by_key(|(d, p, _)| (key(d), *p));
+    }
+
+    pub fn check(&self, day: &str, part: u8, answer: &Answer) -> Status {
+        let actual = text(answer);
+        match self.get(day, part) {
+            None => Status::Missing,
+            Some(expected) if expected == actual => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+                actual,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (day, part, answer) in &self.entries {
+            writeln!(f, "{}\t{}\t{}", day, part, escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+/// Line-by-line diff of an expected and actual answer, with `-` marking
+/// expected lines and `+` actual ones.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    let mut res = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => res += &format!("  {}\n", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    res += &format!("- {}\n", e);
+                }
+                if let Some(a) = a {
+                    res += &format!("+ {}\n", a);
+                }
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::answers::{diff, Answers, AnswersError, Status};
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.set("10", 1, "26397".to_string());
+        answers.set("2", 2, "a\\b\n#.#".to_string());
+        answers.set("2", 1, "150".to_string());
+        answers.sort_by_day(|d| d.parse::<u32>().unwrap());
+        let text = answers.to_string();
+        assert_eq!("2\t1\t150\n2\t2\ta\\\\b\\n#.#\n10\t1\t26397\n", text);
+        assert_eq!(Ok(answers), Answers::parse(&text));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Err(AnswersError {
+                line: 2,
+                message: "part must be 1 or 2"
+            }),
+            Answers::parse("# comment\n1\t3\t10\n")
+        );
+        assert_eq!(
+            Err(AnswersError {
+                line: 1,
+                message: "expected <day>\\t<part>\\t<answer>"
+            }),
+            Answers::parse("1 1 10\n")
+        );
+    }
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse("13\t2\t#.\\n.#\n1\t1\t7\n").unwrap();
+        let grid = Answer::Grid(vec!["#.".to_string(), ".#".to_string()]);
+        assert_eq!(Status::Pass, answers.check("13", 2, &grid));
+        assert_eq!(Status::Missing, answers.check("13", 1, &grid));
+        assert_eq!(
+            Status::Fail {
+                expected: "7".to_string(),
+                actual: "8".to_string()
+            },
+            answers.check("1", 1, &Answer::Int(8))
+        );
+        assert_eq!("  #.\n- .#\n+ ##\n", diff("#.\n.#", "#.\n##"));
+    }
+}
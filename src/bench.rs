@@ -0,0 +1,223 @@
+//! Repeated timing of single parts, and a baseline of earlier medians to
+//! compare them against.
+
+use crate::answers::Answers;
+use crate::runner::{self, format_duration, Outcome};
+use crate::solution::Day;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Summary of the timed runs of one part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    /// Sample standard deviation, zero for a single run.
+    pub stddev: Duration,
+    pub min: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Stats {
+            runs: n,
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            min: sorted[0],
+        }
+    }
+}
+
+/// Parses `lines` once, then solves `part` of `day` `warmup` times untimed
+/// and `runs` times timed. Stops at the first run that does not produce an
+/// answer.
+pub fn bench(
+    day: &Day,
+    part: u8,
+    lines: &[String],
+    warmup: usize,
+    runs: usize,
+) -> Result<Stats, Outcome> {
+    let parsed = runner::catch(|| day.solver.parse(lines))?.map_err(Outcome::Invalid)?;
+    let solve = || {
+        runner::catch(|| match part {
+            1 => day.solver.part1(&*parsed),
+            _ => day.solver.part2(&*parsed),
+        })
+    };
+    for _ in 0..warmup {
+        solve()?;
+    }
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        solve()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::new(&samples))
+}
+
+/// Medians of earlier benchmarks, kept in the answers file format with the
+/// median in nanoseconds as the answer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline(Answers);
+
+impl Baseline {
+    /// Loads `path`, or returns an empty baseline if it does not exist.
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        Answers::load(path).map(Baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        self.0.save(path)
+    }
+
+    pub fn get(&self, day: &str, part: u8) -> Option<Duration> {
+        let nanos = self.0.get(day, part)?.parse().ok()?;
+        Some(Duration::from_nanos(nanos))
+    }
+
+    pub fn set(&mut self, day: &str, part: u8, median: Duration) {
+        self.0.set(day, part, median.as_nanos().to_string());
+    }
+
+    /// Orders the entries by `key` of their day, then by part.
+    pub fn sort_by_day<K: Ord, F: Fn(&str) -> K>(&mut self, key: F) {
+        self.0.sort_by_day(key)
+    }
+}
+
+/// Change from `baseline` to `current` in percent.
+pub fn change(baseline: Duration, current: Duration) -> f64 {
+    if baseline.as_nanos() == 0 {
+        return 0.0;
+    }
+    let (baseline, current) = (baseline.as_nanos() as f64, current.as_nanos() as f64);
+    100.0 * (current - baseline) / baseline
+}
+
+/// Whether `current` is more than `threshold` percent slower than `baseline`.
+pub fn is_regression(baseline: Duration, current: Duration, threshold: f64) -> bool {
+    change(baseline, current) > threshold
+}
+
+pub fn table_header() -> String {
+    format!(
+        "{:>4} {:>4} {:>5} {:>10} {:>10} {:>10} {:>10} {:>10}\n",
+        "Day", "Part", "Runs", "Mean", "Median", "Stddev", "Min", "Baseline"
+    )
+}
+
+/// One table row, with the change of the median against `baseline` and a
+/// marker if it is a regression beyond `threshold` percent.
+pub fn table_row(
+    day: &str,
+    part: u8,
+    stats: &Stats,
+    baseline: Option<Duration>,
+    threshold: f64,
+) -> String {
+    let change = match baseline {
+        Some(baseline) => {
+            let marker = if is_regression(baseline, stats.median, threshold) {
+                " REGRESSION"
+            } else {
+                ""
+            };
+            format!("{:>+9.1}%{}", change(baseline, stats.median), marker)
+        }
+        None => format!("{:>10}", "-"),
+    };
+    format!(
+        "{:>4} {:>4} {:>5} {:>10} {:>10} {:>10} {:>10} {}\n",
+        day,
+        part,
+        stats.runs,
+        format_duration(stats.mean),
+        format_duration(stats.median),
+        format_duration(stats.stddev),
+        format_duration(stats.min),
+        change
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{bench, change, is_regression, table_row, Baseline, Stats};
+    use crate::runner::Outcome;
+    use std::time::Duration;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(&[ms(4), ms(2), ms(6), ms(8)]);
+        assert_eq!(4, stats.runs);
+        assert_eq!(ms(5), stats.mean);
+        assert_eq!(ms(5), stats.median);
+        assert_eq!(ms(2), stats.min);
+        // sqrt(20 / 3) ms
+        assert_eq!(2581, stats.stddev.as_micros());
+
+        let stats = Stats::new(&[ms(3)]);
+        assert_eq!((ms(3), Duration::default()), (stats.median, stats.stddev));
+    }
+
+    #[test]
+    fn baseline() {
+        let mut baseline = Baseline::default();
+        baseline.set("15", 2, Duration::from_nanos(1500));
+        assert_eq!(Some(Duration::from_nanos(1500)), baseline.get("15", 2));
+        assert_eq!(None, baseline.get("15", 1));
+
+        assert_eq!(50.0, change(ms(10), ms(15)));
+        assert!(is_regression(ms(10), ms(12), 10.0));
+        assert!(!is_regression(ms(10), ms(11), 10.0));
+        assert!(!is_regression(ms(10), ms(5), 10.0));
+        assert!(
+            table_row("15", 2, &Stats::new(&[ms(12)]), Some(ms(10)), 10.0)
+                .ends_with("+20.0% REGRESSION\n")
+        );
+        assert!(
+            table_row("15", 2, &Stats::new(&[ms(9)]), Some(ms(10)), 10.0).ends_with(" -10.0%\n")
+        );
+    }
+
+    #[test]
+    fn runs() {
+        let day = crate::day(2021, "1").unwrap();
+        let lines = ["1", "2", "3", "4"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(5, bench(day, 2, &lines, 1, 5).unwrap().runs);
+        let lines = vec!["x".to_string()];
+        assert!(matches!(
+            bench(day, 1, &lines, 1, 5),
+            Err(Outcome::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+use std::cell::RefCell;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared flag telling a day running on a worker thread to give up.
+#[derive(Clone, Debug, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+/// Unwind payload of a day that stopped because its token was cancelled.
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl Token {
+    pub fn new() -> Token {
+        Token::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Makes `token` the one `check` looks at while `f` runs on this thread.
+pub fn with_token<T, F: FnOnce() -> T>(token: &Token, f: F) -> T {
+    let previous = CURRENT.with(|c| c.replace(Some(token.clone())));
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    CURRENT.with(|c| *c.borrow_mut() = previous);
+    res.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// The token `check` looks at on this thread, or one that is never
+/// cancelled. Days that spread their work over other threads, e.g. with
+/// rayon, hand it to those threads with `with_token` so their checks see it.
+pub fn current() -> Token {
+    CURRENT.with(|c| c.borrow().clone()).unwrap_or_default()
+}
+
+/// Unwinds with `Cancelled` if the current thread's token has been
+/// cancelled. Long searches call this in their loops; it does nothing when
+/// no token is set.
+pub fn check() {
+    let cancelled = CURRENT.with(|c| c.borrow().as_ref().is_some_and(Token::is_cancelled));
+    if cancelled {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cancel::{check, current, with_token, Cancelled, Token};
+    use rayon::prelude::*;
+    use std::panic;
+
+    #[test]
+    fn cancel() {
+        let token = Token::new();
+        check();
+        with_token(&token, check);
+        token.cancel();
+        let payload = panic::catch_unwind(|| with_token(&token, check)).unwrap_err();
+        assert!(payload.is::<Cancelled>());
+        check();
+    }
+
+    #[test]
+    fn other_threads() {
+        let token = Token::new();
+        token.cancel();
+        assert!(!current().is_cancelled());
+        let payload = panic::catch_unwind(|| {
+            with_token(&token, || {
+                let token = current();
+                (0..4)
+                    .into_par_iter()
+                    .for_each(|_| with_token(&token, check));
+            })
+        })
+        .unwrap_err();
+        assert!(payload.is::<Cancelled>());
+    }
+}
@@ -0,0 +1,1139 @@
+use advent::input::{self, Source};
+use advent::log::Filter;
+use advent::runner::Parts;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const ANSWERS: &str = "answers.txt";
+pub const BASELINE: &str = "bench.txt";
+pub const SESSION_FILE: &str = ".aoc-session";
+/// Time between polls of `watch`.
+pub const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+pub const USAGE: &str = "\
+usage: advent run <year> <days> [options]
+       advent verify [<year> [<days>]] [--answers <file>] [options]
+       advent record [<year> [<days>]] [--answers <file>] [options]
+       advent bench <year> <days> [bench options] [options]
+       advent fetch <year> <days> [fetch options]
+       advent watch <year> <day> [--interval <secs>] [options]
+       advent new <year> <day>
+
+Every command takes the year first, e.g. `advent run 2021 15`. Inputs are read
+from inputs/<year>/N.txt.
+
+`verify` checks the answers against an answers file, answers.txt by default,
+and `record` writes them into it. Both run every day of the year if no days
+are given, and every day of every year if no year is given.
+
+`bench` parses each day once and times every selected part repeatedly,
+printing the mean, median, standard deviation and minimum. Medians are
+compared against a baseline file, and `bench` exits with status 1 if any part
+got slower than the threshold allows.
+
+`fetch` downloads puzzle inputs into inputs/<year>/N.txt. An input that is
+already there is never downloaded again. The session token is read from
+$AOC_SESSION, or else from .aoc-session. Downloads run `curl`, which must be
+on the PATH.
+
+`watch` solves a day and its examples, then polls its input file and
+examples/<year>/<day>/ every half second by default and solves them again
+whenever they change. Answers that differ from the previous run are marked.
+It does not rebuild the solver; run it under `cargo watch` for that.
+
+`new` creates src/y<year>/day_N.rs from templates/day.rs and registers it in
+src/y<year>/mod.rs, after any other solvers of day N, creating the module of
+a new year as well. It must be run from the crate root, and never overwrites
+an existing module. <day> is a day number with an optional letter for another
+solver of the same day, e.g. 15 or 15b.
+
+<days> is a comma-separated list of:
+  N        a single day, e.g. 15 or 24b
+  A..B     days A to B, exclusive
+  A..=B    days A to B, inclusive
+  all      every day
+
+Options:
+  --part <1|2>       solve only one part
+  --input <file>     read the input from <file>, or from stdin if <file> is -
+  --input-dir <dir>  read day N from <dir>/<year>/N.txt instead of inputs
+  --time             print parse and solve times for every day; not for
+                     `bench` or `watch`
+  --timeout <secs>   give up on a day that runs longer than <secs> seconds;
+                     not for `bench`
+  --alloc-stats      count the allocations, bytes allocated and peak live
+                     memory of every parse and part; not for `bench`
+  --format <fmt>     `text`, or `json` for one object per part with its status
+                     (ok, panic or unimplemented); only for `run`, and --time
+                     is left out since every object carries its duration
+  -v, --verbose      show more log events on stderr: -v for info, -vv for
+                     debug and -vvv for trace; repeatable
+  --log <filter>     which log events to show, overriding $RUST_LOG, as
+                     comma-separated directives `<level>` or
+                     `<module>=<level>`, e.g. `info,day_25=trace`; a module
+                     without a level shows everything. Only warnings and
+                     errors are shown by default
+
+Bench options:
+  --runs <n>         timed runs per part, 20 by default
+  --warmup <n>       untimed runs before them, 3 by default
+  --baseline <file>  medians to compare against, bench.txt by default
+  --save             write the new medians into the baseline
+  --threshold <pct>  slowdown that counts as a regression, 10 by default
+
+Fetch options:
+  --input-dir <dir>      download into <dir>/<year> instead of inputs/<year>
+  --base-url <url>       server to download from, $AOC_BASE_URL or
+                         https://adventofcode.com by default
+  --session-file <file>  read the session token from <file>
+
+A part that panics or is not implemented, or a day that times out or cannot
+parse its input, does not stop the other days; all of them are listed at the
+end, and `run` exits with status 1 unless the only failures are unimplemented
+parts.
+
+Ranges and `all` run the first solver listed for each day; day 24 of 2021 has
+both day_24 (`24`) and day_24b (`24b`), and ranges pick `24b`.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Years and solver ids.
+    pub days: Vec<(u32, &'static str)>,
+    pub parts: Parts,
+    pub input: Source,
+    /// Print a table of parse and solve times after the answers.
+    pub time: bool,
+    pub format: Format,
+    /// Abandon a day that runs longer than this.
+    pub timeout: Option<Duration>,
+    /// Count the allocations of every phase and print them after the
+    /// answers.
+    pub alloc_stats: bool,
+    /// How many levels to raise the default log level by.
+    pub verbose: u8,
+    /// Overrides $RUST_LOG.
+    pub log: Option<Filter>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchOptions {
+    pub runs: usize,
+    pub warmup: usize,
+    pub baseline: PathBuf,
+    /// Write the medians into the baseline after comparing.
+    pub save: bool,
+    /// Slowdown of the median, in percent, that counts as a regression.
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> BenchOptions {
+        BenchOptions {
+            runs: 20,
+            warmup: 3,
+            baseline: PathBuf::from(BASELINE),
+            save: false,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    Help,
+    Run(RunOptions),
+    /// Compare the answers against the answers file.
+    Verify {
+        options: RunOptions,
+        answers: PathBuf,
+    },
+    /// Write the answers into the answers file.
+    Record {
+        options: RunOptions,
+        answers: PathBuf,
+    },
+    /// Time the parts repeatedly and compare against a baseline.
+    Bench {
+        options: RunOptions,
+        bench: BenchOptions,
+    },
+    /// Download the inputs of the given day numbers into `dir`.
+    Fetch {
+        year: u32,
+        days: Vec<u32>,
+        dir: PathBuf,
+        /// Overrides $AOC_BASE_URL.
+        base_url: Option<String>,
+        session_file: PathBuf,
+    },
+    /// Re-run a single day whenever its input or examples change.
+    Watch {
+        options: RunOptions,
+        interval: Duration,
+    },
+    /// Generate and register the module of a new solver.
+    New {
+        year: u32,
+        id: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CliError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingArgument(&'static str),
+    UnexpectedArgument(String),
+    UnknownDay(String),
+    InvalidPart(String),
+    InvalidFormat(String),
+    InvalidTimeout(String),
+    InvalidCount(String),
+    InvalidThreshold(String),
+    UnknownYear(String),
+    InvalidLog(String),
+    InvalidInterval(String),
+    SingleInput,
+    /// `watch` was given more than one day.
+    SingleWatch,
+    /// `watch` cannot re-read stdin.
+    WatchStdin,
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::MissingCommand => write!(f, "no command given"),
+            CliError::UnknownCommand(c) => write!(f, "unknown command `{}`", c),
+            CliError::MissingArgument(a) => write!(f, "missing argument {}", a),
+            CliError::UnexpectedArgument(a) => write!(f, "unexpected argument `{}`", a),
+            CliError::UnknownDay(d) => write!(f, "unknown day `{}`", d),
+            CliError::UnknownYear(y) => write!(f, "unknown year `{}`", y),
+            CliError::InvalidPart(p) => write!(f, "invalid part `{}`, expected 1 or 2", p),
+            CliError::InvalidFormat(format) => {
+                write!(f, "invalid format `{}`, expected text or json", format)
+            }
+            CliError::InvalidTimeout(t) => {
+                write!(f, "invalid timeout `{}`, expected a number of seconds", t)
+            }
+            CliError::InvalidCount(n) => write!(f, "invalid count `{}`", n),
+            CliError::InvalidThreshold(t) => {
+                write!(f, "invalid threshold `{}`, expected a percentage", t)
+            }
+            CliError::InvalidLog(e) => write!(f, "invalid log filter: {}", e),
+            CliError::InvalidInterval(t) => {
+                write!(f, "invalid interval `{}`, expected a number of seconds", t)
+            }
+            CliError::SingleInput => write!(f, "--input can only be used with a single day"),
+            CliError::SingleWatch => write!(f, "watch takes a single day"),
+            CliError::WatchStdin => write!(f, "watch cannot read the input from stdin"),
+        }
+    }
+}
+
+fn day_number(id: &str) -> Option<u32> {
+    let digits = id
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>();
+    digits.parse().ok()
+}
+
+fn default_for(number: u32, days: &[&'static str]) -> Option<&'static str> {
+    days.iter()
+        .copied()
+        .find(|id| day_number(id) == Some(number))
+}
+
+fn parse_range(spec: &str, days: &[&'static str]) -> Option<Result<Vec<&'static str>, CliError>> {
+    let (start, end, inclusive) = if let Some(i) = spec.find("..=") {
+        (&spec[..i], &spec[i + 3..], true)
+    } else if let Some(i) = spec.find("..") {
+        (&spec[..i], &spec[i + 2..], false)
+    } else {
+        return None;
+    };
+
+    let bound = |s: &str| {
+        s.parse::<u32>()
+            .map_err(|_| CliError::UnknownDay(spec.to_string()))
+    };
+    let (start, end) = match (bound(start), bound(end)) {
+        (Ok(start), Ok(end)) => (start, if inclusive { end + 1 } else { end }),
+        (Err(e), _) | (_, Err(e)) => return Some(Err(e)),
+    };
+    if start >= end {
+        return Some(Err(CliError::UnknownDay(spec.to_string())));
+    }
+
+    Some(
+        (start..end)
+            .map(|n| default_for(n, days).ok_or_else(|| CliError::UnknownDay(n.to_string())))
+            .collect(),
+    )
+}
+
+fn parse_days(spec: &str, days: &[&'static str]) -> Result<Vec<&'static str>, CliError> {
+    let mut res = Vec::new();
+    for item in spec.split(',') {
+        if item == "all" {
+            let mut numbers = days
+                .iter()
+                .filter_map(|id| day_number(id))
+                .collect::<Vec<_>>();
+            numbers.dedup();
+            res.extend(numbers.into_iter().filter_map(|n| default_for(n, days)));
+        } else if let Some(range) = parse_range(item, days) {
+            res.extend(range?);
+        } else if let Some(id) = days.iter().find(|id| **id == item) {
+            res.push(*id);
+        } else {
+            return Err(CliError::UnknownDay(item.to_string()));
+        }
+    }
+    Ok(res)
+}
+
+/// The known solver ids of each year; the first id for each day number is
+/// its default.
+pub type Years = [(u32, Vec<&'static str>)];
+
+/// The positional `<year> <days>` arguments.
+#[derive(Default)]
+struct Selection<'a> {
+    year: Option<(u32, &'a [&'static str])>,
+    days: Option<Vec<&'static str>>,
+}
+
+impl<'a> Selection<'a> {
+    /// Takes `arg` as the next positional argument, or returns false if
+    /// both have been given.
+    fn push(&mut self, arg: &str, years: &'a Years) -> Result<bool, CliError> {
+        match self.year {
+            None => {
+                let (year, ids) = years
+                    .iter()
+                    .find(|(year, _)| year.to_string() == arg)
+                    .ok_or_else(|| CliError::UnknownYear(arg.to_string()))?;
+                self.year = Some((*year, ids));
+            }
+            Some((_, ids)) if self.days.is_none() => self.days = Some(parse_days(arg, ids)?),
+            Some(_) => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// The selected days. With `all`, a missing `<days>` selects every day
+    /// of the year, and a missing `<year>` every day of every year.
+    fn days(self, years: &Years, all: bool) -> Result<Vec<(u32, &'static str)>, CliError> {
+        let every = |year: u32, ids: &[&'static str]| -> Result<Vec<_>, CliError> {
+            Ok(parse_days("all", ids)?
+                .into_iter()
+                .map(|id| (year, id))
+                .collect())
+        };
+        match (self.year, self.days) {
+            (Some((year, _)), Some(days)) => Ok(days.into_iter().map(|id| (year, id)).collect()),
+            (Some((year, ids)), None) if all => every(year, ids),
+            (None, _) if all => Ok(years
+                .iter()
+                .map(|(year, ids)| every(*year, ids))
+                .collect::<Result<Vec<_>, _>>()?
+                .concat()),
+            (Some(_), None) => Err(CliError::MissingArgument("<days>")),
+            (None, _) => Err(CliError::MissingArgument("<year>")),
+        }
+    }
+}
+
+fn parse_part(part: &str) -> Result<Parts, CliError> {
+    match part {
+        "1" => Ok(Parts::One),
+        "2" => Ok(Parts::Two),
+        _ => Err(CliError::InvalidPart(part.to_string())),
+    }
+}
+
+fn parse_format(format: &str) -> Result<Format, CliError> {
+    match format {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(CliError::InvalidFormat(format.to_string())),
+    }
+}
+
+fn parse_timeout(timeout: &str) -> Result<Duration, CliError> {
+    match timeout.parse::<f64>() {
+        Ok(secs) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(CliError::InvalidTimeout(timeout.to_string())),
+    }
+}
+
+fn parse_count(count: &str, min: usize) -> Result<usize, CliError> {
+    match count.parse() {
+        Ok(n) if n >= min => Ok(n),
+        _ => Err(CliError::InvalidCount(count.to_string())),
+    }
+}
+
+fn parse_threshold(threshold: &str) -> Result<f64, CliError> {
+    match threshold.trim_end_matches('%').parse::<f64>() {
+        Ok(pct) if pct >= 0.0 && pct.is_finite() => Ok(pct),
+        _ => Err(CliError::InvalidThreshold(threshold.to_string())),
+    }
+}
+
+/// The subcommands sharing `parse_run`, which differ in the options they
+/// accept.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Run,
+    /// `verify` and `record`, which take an answers file and default to all
+    /// days.
+    Check,
+    Bench,
+    Watch,
+}
+
+struct Args {
+    options: RunOptions,
+    answers: PathBuf,
+    bench: BenchOptions,
+    interval: Duration,
+}
+
+fn parse_run<I: Iterator<Item = String>>(
+    args: &mut I,
+    years: &Years,
+    mode: Mode,
+) -> Result<Args, CliError> {
+    let check = mode == Mode::Check;
+    let mut selected = Selection::default();
+    let mut parts = Parts::Both;
+    let mut input = Source::Default;
+    let mut time = false;
+    let mut format = Format::Text;
+    let mut timeout = None;
+    let mut alloc_stats = false;
+    let mut verbose = 0u8;
+    let mut log = None;
+    let mut answers = PathBuf::from(ANSWERS);
+    let mut bench = BenchOptions::default();
+    let is_bench = mode == Mode::Bench;
+    let mut interval = WATCH_INTERVAL;
+    while let Some(arg) = args.next() {
+        if !is_bench && mode != Mode::Watch && (arg == "--time" || arg == "-t") {
+            time = true;
+        } else if arg == "--input" || arg == "-i" {
+            let path = args.next().ok_or(CliError::MissingArgument("to --input"))?;
+            input = if path == "-" {
+                Source::Stdin
+            } else {
+                Source::File(PathBuf::from(path))
+            };
+        } else if arg == "--input-dir" {
+            let dir = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --input-dir"))?;
+            input = Source::Dir(PathBuf::from(dir));
+        } else if arg == "--part" || arg == "-p" {
+            let part = args.next().ok_or(CliError::MissingArgument("to --part"))?;
+            parts = parse_part(&part)?;
+        } else if let Some(part) = arg.strip_prefix("--part=") {
+            parts = parse_part(part)?;
+        } else if mode == Mode::Watch && arg == "--interval" {
+            let t = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --interval"))?;
+            interval = parse_timeout(&t).map_err(|_| CliError::InvalidInterval(t.to_string()))?;
+        } else if !is_bench && mode != Mode::Watch && arg == "--alloc-stats" {
+            alloc_stats = true;
+        } else if arg == "--verbose" {
+            verbose = verbose.saturating_add(1);
+        } else if arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v') {
+            verbose = verbose.saturating_add((arg.len() - 1).min(u8::MAX as usize) as u8);
+        } else if arg == "--log" {
+            let spec = args.next().ok_or(CliError::MissingArgument("to --log"))?;
+            log = Some(Filter::parse(&spec).map_err(CliError::InvalidLog)?);
+        } else if !is_bench && arg == "--timeout" {
+            let t = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --timeout"))?;
+            timeout = Some(parse_timeout(&t)?);
+        } else if mode == Mode::Run && arg == "--format" {
+            let f = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --format"))?;
+            format = parse_format(&f)?;
+        } else if let (Mode::Run, Some(f)) = (mode, arg.strip_prefix("--format=")) {
+            format = parse_format(f)?;
+        } else if check && arg == "--answers" {
+            let path = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --answers"))?;
+            answers = PathBuf::from(path);
+        } else if is_bench && arg == "--runs" {
+            let n = args.next().ok_or(CliError::MissingArgument("to --runs"))?;
+            bench.runs = parse_count(&n, 1)?;
+        } else if is_bench && arg == "--warmup" {
+            let n = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --warmup"))?;
+            bench.warmup = parse_count(&n, 0)?;
+        } else if is_bench && arg == "--baseline" {
+            let path = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --baseline"))?;
+            bench.baseline = PathBuf::from(path);
+        } else if is_bench && arg == "--save" {
+            bench.save = true;
+        } else if is_bench && arg == "--threshold" {
+            let t = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --threshold"))?;
+            bench.threshold = parse_threshold(&t)?;
+        } else if !selected.push(&arg, years)? {
+            return Err(CliError::UnexpectedArgument(arg));
+        }
+    }
+    let days = selected.days(years, check)?;
+    if mode == Mode::Watch && days.len() > 1 {
+        return Err(CliError::SingleWatch);
+    }
+    if mode == Mode::Watch && input == Source::Stdin {
+        return Err(CliError::WatchStdin);
+    }
+    if input.is_single() && days.len() > 1 {
+        return Err(CliError::SingleInput);
+    }
+    let options = RunOptions {
+        days,
+        parts,
+        input,
+        time,
+        format,
+        timeout,
+        alloc_stats,
+        verbose,
+        log,
+    };
+    Ok(Args {
+        options,
+        answers,
+        bench,
+        interval,
+    })
+}
+
+fn parse_fetch<I: Iterator<Item = String>>(
+    args: &mut I,
+    years: &Years,
+) -> Result<Command, CliError> {
+    let mut selected = Selection::default();
+    let mut dir = PathBuf::from(input::DIR);
+    let mut base_url = None;
+    let mut session_file = PathBuf::from(SESSION_FILE);
+    while let Some(arg) = args.next() {
+        if arg == "--input-dir" {
+            let d = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --input-dir"))?;
+            dir = PathBuf::from(d);
+        } else if arg == "--base-url" {
+            let url = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --base-url"))?;
+            base_url = Some(url);
+        } else if arg == "--session-file" {
+            let path = args
+                .next()
+                .ok_or(CliError::MissingArgument("to --session-file"))?;
+            session_file = PathBuf::from(path);
+        } else if !selected.push(&arg, years)? {
+            return Err(CliError::UnexpectedArgument(arg));
+        }
+    }
+    let days = selected.days(years, false)?;
+    // 24 and 24b share an input
+    let mut numbers = days
+        .iter()
+        .filter_map(|(_, id)| day_number(id))
+        .collect::<Vec<_>>();
+    numbers.dedup();
+    Ok(Command::Fetch {
+        year: days[0].0,
+        days: numbers,
+        dir,
+        base_url,
+        session_file,
+    })
+}
+
+/// Parses the command line, excluding the program name.
+pub fn parse<I: IntoIterator<Item = String>>(args: I, years: &Years) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        None => Err(CliError::MissingCommand),
+        Some("help") | Some("-h") | Some("--help") => Ok(Command::Help),
+        Some("run") => Ok(Command::Run(
+            parse_run(&mut args, years, Mode::Run)?.options,
+        )),
+        Some("verify") => {
+            let Args {
+                options, answers, ..
+            } = parse_run(&mut args, years, Mode::Check)?;
+            Ok(Command::Verify { options, answers })
+        }
+        Some("record") => {
+            let Args {
+                options, answers, ..
+            } = parse_run(&mut args, years, Mode::Check)?;
+            Ok(Command::Record { options, answers })
+        }
+        Some("bench") => {
+            let Args { options, bench, .. } = parse_run(&mut args, years, Mode::Bench)?;
+            Ok(Command::Bench { options, bench })
+        }
+        Some("fetch") => parse_fetch(&mut args, years),
+        Some("watch") => {
+            let Args {
+                options, interval, ..
+            } = parse_run(&mut args, years, Mode::Watch)?;
+            Ok(Command::Watch { options, interval })
+        }
+        Some("new") => {
+            let year = args.next().ok_or(CliError::MissingArgument("<year>"))?;
+            let year = year.parse().map_err(|_| CliError::UnknownYear(year))?;
+            let id = args.next().ok_or(CliError::MissingArgument("<day>"))?;
+            match args.next() {
+                Some(arg) => Err(CliError::UnexpectedArgument(arg)),
+                None => Ok(Command::New { year, id }),
+            }
+        }
+        Some(c) => Err(CliError::UnknownCommand(c.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{parse, BenchOptions, CliError, Command, Format, RunOptions};
+    use advent::input::Source;
+    use advent::log::Filter;
+    use advent::runner::Parts;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn run(args: &[&str]) -> Result<Command, CliError> {
+        let years = vec![
+            (2020, vec!["1", "2"]),
+            (2021, vec!["1", "2", "3", "24b", "24", "25"]),
+        ];
+        parse(args.iter().map(|s| s.to_string()), &years)
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "2")],
+                parts: Parts::Both,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "2"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "1"), (2021, "2")],
+                parts: Parts::Two,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "1..3", "--part", "2"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "24b"), (2021, "25")],
+                parts: Parts::One,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "--part=1", "24..=25"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "24"), (2021, "3")],
+                parts: Parts::Both,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "24,3"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![
+                    (2021, "1"),
+                    (2021, "2"),
+                    (2021, "3"),
+                    (2021, "24b"),
+                    (2021, "25")
+                ],
+                parts: Parts::Both,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "all"])
+        );
+    }
+
+    #[test]
+    fn inputs() {
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "3")],
+                parts: Parts::Both,
+                input: Source::Stdin,
+                time: false,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "3", "--input", "-"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "1"), (2021, "2")],
+                parts: Parts::Both,
+                input: Source::Dir(PathBuf::from("inputs")),
+                time: false,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "1..=2", "--input-dir", "inputs"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "1"), (2021, "2"), (2021, "3")],
+                parts: Parts::Both,
+                input: Source::Default,
+                time: true,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: true,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "1..=3", "--time", "--alloc-stats"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "25")],
+                parts: Parts::Both,
+                input: Source::Default,
+                time: false,
+                format: Format::Json,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "25", "--format", "json"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "24b")],
+                parts: Parts::Both,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                timeout: Some(Duration::from_millis(2500)),
+                alloc_stats: false,
+                verbose: 0,
+                log: None,
+            })),
+            run(&["run", "2021", "24b", "--timeout", "2.5"])
+        );
+        assert_eq!(
+            Err(CliError::SingleInput),
+            run(&["run", "2021", "1..=2", "-i", "1_input"])
+        );
+        assert_eq!(
+            Ok(Command::Run(RunOptions {
+                days: vec![(2021, "25")],
+                parts: Parts::Both,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                timeout: None,
+                alloc_stats: false,
+                verbose: 3,
+                log: Some(Filter::parse("info,day_25=trace").unwrap()),
+            })),
+            run(&[
+                "run",
+                "2021",
+                "25",
+                "-vv",
+                "--log",
+                "info,day_25=trace",
+                "--verbose"
+            ])
+        );
+        for count in [255, 256, 300] {
+            let flag = format!("-{}", "v".repeat(count));
+            match run(&["run", "2021", "25", &flag]) {
+                Ok(Command::Run(options)) => assert_eq!(u8::MAX, options.verbose),
+                other => panic!("{:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(
+            Ok(Command::Verify {
+                options: RunOptions {
+                    days: vec![
+                        (2020, "1"),
+                        (2020, "2"),
+                        (2021, "1"),
+                        (2021, "2"),
+                        (2021, "3"),
+                        (2021, "24b"),
+                        (2021, "25")
+                    ],
+                    parts: Parts::Both,
+                    input: Source::Default,
+                    time: false,
+                    format: Format::Text,
+                    timeout: None,
+                    alloc_stats: false,
+                    verbose: 0,
+                    log: None,
+                },
+                answers: PathBuf::from("answers.txt"),
+            }),
+            run(&["verify"])
+        );
+        match run(&["verify", "2020"]) {
+            Ok(Command::Verify { options, .. }) => {
+                assert_eq!(vec![(2020, "1"), (2020, "2")], options.days)
+            }
+            command => panic!("{:?}", command),
+        }
+        assert_eq!(
+            Ok(Command::Record {
+                options: RunOptions {
+                    days: vec![(2021, "2")],
+                    parts: Parts::Both,
+                    input: Source::Default,
+                    time: false,
+                    format: Format::Text,
+                    timeout: None,
+                    alloc_stats: false,
+                    verbose: 0,
+                    log: None,
+                },
+                answers: PathBuf::from("a.txt"),
+            }),
+            run(&["record", "2021", "2", "--answers", "a.txt"])
+        );
+    }
+
+    #[test]
+    fn bench() {
+        let options = RunOptions {
+            days: vec![(2021, "3")],
+            parts: Parts::Two,
+            input: Source::Default,
+            time: false,
+            format: Format::Text,
+            timeout: None,
+            alloc_stats: false,
+            verbose: 0,
+            log: None,
+        };
+        assert_eq!(
+            Ok(Command::Bench {
+                options: options.clone(),
+                bench: BenchOptions::default(),
+            }),
+            run(&["bench", "2021", "3", "--part", "2"])
+        );
+        assert_eq!(
+            Ok(Command::Bench {
+                options,
+                bench: BenchOptions {
+                    runs: 5,
+                    warmup: 0,
+                    baseline: PathBuf::from("old.txt"),
+                    save: true,
+                    threshold: 2.5,
+                },
+            }),
+            run(&[
+                "bench",
+                "2021",
+                "3",
+                "--part=2",
+                "--runs",
+                "5",
+                "--warmup",
+                "0",
+                "--baseline",
+                "old.txt",
+                "--save",
+                "--threshold",
+                "2.5%"
+            ])
+        );
+        assert_eq!(
+            Err(CliError::InvalidCount("0".to_string())),
+            run(&["bench", "2021", "3", "--runs", "0"])
+        );
+        assert_eq!(
+            Err(CliError::InvalidThreshold("fast".to_string())),
+            run(&["bench", "2021", "3", "--threshold", "fast"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--runs".to_string())),
+            run(&["run", "2021", "3", "--runs", "5"])
+        );
+        assert_eq!(Err(CliError::MissingArgument("<year>")), run(&["bench"]));
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--alloc-stats".to_string())),
+            run(&["bench", "2021", "3", "--alloc-stats"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--timeout".to_string())),
+            run(&["bench", "2021", "3", "--timeout", "5"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--time".to_string())),
+            run(&["bench", "2021", "3", "--time"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("-t".to_string())),
+            run(&["bench", "2021", "3", "-t"])
+        );
+    }
+
+    #[test]
+    fn fetch() {
+        assert_eq!(
+            Ok(Command::Fetch {
+                year: 2021,
+                days: vec![1, 2, 24],
+                dir: PathBuf::from("inputs"),
+                base_url: None,
+                session_file: PathBuf::from(".aoc-session"),
+            }),
+            run(&["fetch", "2021", "1,2,24,24b"])
+        );
+        assert_eq!(
+            Ok(Command::Fetch {
+                year: 2021,
+                days: vec![3],
+                dir: PathBuf::from("cache"),
+                base_url: Some("http://localhost:8000".to_string()),
+                session_file: PathBuf::from("token"),
+            }),
+            run(&[
+                "fetch",
+                "2021",
+                "--base-url",
+                "http://localhost:8000",
+                "3",
+                "--input-dir",
+                "cache",
+                "--session-file",
+                "token"
+            ])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--part".to_string())),
+            run(&["fetch", "2021", "3", "--part", "1"])
+        );
+        assert_eq!(
+            Err(CliError::MissingArgument("<days>")),
+            run(&["fetch", "2021"])
+        );
+    }
+
+    #[test]
+    fn watch() {
+        let options = RunOptions {
+            days: vec![(2021, "24")],
+            parts: Parts::Both,
+            input: Source::Dir(PathBuf::from("inputs")),
+            time: false,
+            format: Format::Text,
+            timeout: None,
+            alloc_stats: false,
+            verbose: 0,
+            log: None,
+        };
+        assert_eq!(
+            Ok(Command::Watch {
+                options: options.clone(),
+                interval: Duration::from_millis(500),
+            }),
+            run(&["watch", "2021", "24", "--input-dir", "inputs"])
+        );
+        assert_eq!(
+            Ok(Command::Watch {
+                options,
+                interval: Duration::from_secs(2),
+            }),
+            run(&[
+                "watch",
+                "2021",
+                "--interval",
+                "2",
+                "24",
+                "--input-dir",
+                "inputs"
+            ])
+        );
+        assert_eq!(Err(CliError::SingleWatch), run(&["watch", "2021", "1..=2"]));
+        assert_eq!(
+            Err(CliError::WatchStdin),
+            run(&["watch", "2021", "1", "-i", "-"])
+        );
+        assert_eq!(
+            Err(CliError::InvalidInterval("0".to_string())),
+            run(&["watch", "2021", "1", "--interval", "0"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--interval".to_string())),
+            run(&["run", "2021", "1", "--interval", "1"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--time".to_string())),
+            run(&["watch", "2021", "1", "--time"])
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Ok(Command::New {
+                year: 2022,
+                id: "15b".to_string()
+            }),
+            run(&["new", "2022", "15b"])
+        );
+        assert_eq!(Err(CliError::MissingArgument("<year>")), run(&["new"]));
+        assert_eq!(
+            Err(CliError::MissingArgument("<day>")),
+            run(&["new", "2021"])
+        );
+        assert_eq!(
+            Err(CliError::UnknownYear("next".to_string())),
+            run(&["new", "next", "1"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("16".to_string())),
+            run(&["new", "2021", "15", "16"])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Err(CliError::MissingCommand), run(&[]));
+        assert_eq!(
+            Err(CliError::UnknownDay("26".to_string())),
+            run(&["run", "2021", "26"])
+        );
+        assert_eq!(
+            Err(CliError::UnknownDay("4".to_string())),
+            run(&["run", "2021", "1..=4"])
+        );
+        assert_eq!(
+            Err(CliError::UnknownDay("3..1".to_string())),
+            run(&["run", "2021", "3..1"])
+        );
+        assert_eq!(
+            Err(CliError::InvalidPart("3".to_string())),
+            run(&["run", "2021", "1", "--part", "3"])
+        );
+        assert_eq!(Err(CliError::MissingArgument("<year>")), run(&["run"]));
+        assert_eq!(
+            Err(CliError::MissingArgument("<days>")),
+            run(&["run", "2021"])
+        );
+        assert_eq!(
+            Err(CliError::UnknownYear("15".to_string())),
+            run(&["run", "15", "1"])
+        );
+        assert_eq!(
+            Err(CliError::UnknownDay("3".to_string())),
+            run(&["run", "2020", "3"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--answers".to_string())),
+            run(&["run", "2021", "1", "--answers", "a.txt"])
+        );
+        assert_eq!(
+            Err(CliError::InvalidTimeout("-1".to_string())),
+            run(&["run", "2021", "1", "--timeout", "-1"])
+        );
+        assert_eq!(
+            Err(CliError::InvalidFormat("xml".to_string())),
+            run(&["run", "2021", "1", "--format=xml"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("--format".to_string())),
+            run(&["verify", "2021", "1", "--format", "json"])
+        );
+        assert_eq!(
+            Err(CliError::InvalidLog("unknown log level `loud`".to_string())),
+            run(&["run", "2021", "1", "--log", "day_1=loud"])
+        );
+        assert_eq!(
+            Err(CliError::UnexpectedArgument("-vx".to_string())),
+            run(&["run", "2021", "1", "-vx"])
+        );
+        assert_eq!(
+            Err(CliError::UnknownCommand("walk".to_string())),
+            run(&["walk"])
+        );
+    }
+}
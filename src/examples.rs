@@ -0,0 +1,223 @@
+//! Puzzle examples kept as fixtures. `examples/<year>/<day>/exN.txt` holds
+//! an example input and `answers.txt` next to it its expected answers, one
+//! `exN\t<part>\t<answer>` line per known part, in the format of
+//! [`Answers`]. Adding an example needs no code: the test in this module
+//! discovers every fixture and solves it.
+
+use crate::answers::{self, Answers, Status};
+use crate::input::{InputError, Source};
+use crate::runner::{self, Parts};
+use crate::solution::Day;
+use std::path::{Path, PathBuf};
+
+/// The fixture directory, relative to the crate root.
+pub const DIR: &str = "examples";
+
+const ANSWERS: &str = "answers.txt";
+
+/// One example input of a day and its expected answers.
+pub struct Example {
+    pub day: &'static Day,
+    /// File stem, e.g. `ex1`.
+    pub name: String,
+    pub path: PathBuf,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    fn parts(&self) -> Parts {
+        match (&self.part1, &self.part2) {
+            (Some(_), None) => Parts::One,
+            (None, Some(_)) => Parts::Two,
+            _ => Parts::Both,
+        }
+    }
+
+    /// Solves the parts with an expected answer and compares them. A part
+    /// that fails to produce an answer fails with its outcome as the actual
+    /// text.
+    pub fn check(&self) -> Result<Vec<(u8, Status)>, InputError> {
+        let result = runner::run(
+            self.day,
+            self.parts(),
+            &Source::File(self.path.clone()),
+            None,
+        )?;
+        Ok(result
+            .parts()
+            .map(|(part, result)| {
+                let expected = if part == 1 { &self.part1 } else { &self.part2 };
+                let expected = expected.clone().unwrap_or_default();
+                let actual = match result.outcome.answer() {
+                    Some(answer) => answers::text(answer),
+                    None => result.outcome.to_string(),
+                };
+                let status = if expected == actual {
+                    Status::Pass
+                } else {
+                    Status::Fail { expected, actual }
+                };
+                (part, status)
+            })
+            .collect())
+    }
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = std::fs::read_dir(dir)
+        .and_then(|entries| entries.map(|e| e.map(|e| e.path())).collect())
+        .map_err(|e| format!("cannot read `{}`: {}", dir.display(), e))?;
+    Ok(entries)
+}
+
+fn examples_of(day: &'static Day, dir: &Path) -> Result<Vec<Example>, String> {
+    let answers = Answers::load(&dir.join(ANSWERS))?;
+    let mut examples = Vec::new();
+    for path in read_dir(dir)? {
+        let name = match path.file_stem().and_then(|s| s.to_str()) {
+            Some(name) if path.extension().is_some_and(|e| e == "txt") => name,
+            _ => continue,
+        };
+        if path.file_name().is_some_and(|f| f == ANSWERS) {
+            continue;
+        }
+        let example = Example {
+            day,
+            name: name.to_string(),
+            part1: answers.get(name, 1).map(str::to_string),
+            part2: answers.get(name, 2).map(str::to_string),
+            path,
+        };
+        if example.part1.is_none() && example.part2.is_none() {
+            return Err(format!(
+                "`{}` has no answers in `{}`",
+                example.path.display(),
+                dir.join(ANSWERS).display()
+            ));
+        }
+        examples.push(example);
+    }
+    Ok(examples)
+}
+
+fn subdirs(dir: &Path) -> Result<Vec<(PathBuf, String)>, String> {
+    Ok(read_dir(dir)?
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            let name = name.to_string();
+            (path, name)
+        })
+        .collect())
+}
+
+/// Finds every example under `dir`, ordered by year, day and then by name
+/// with `ex2` before `ex10`. A missing `dir` has no examples.
+pub fn discover(dir: &Path) -> Result<Vec<Example>, String> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut examples = Vec::new();
+    for (path, year) in subdirs(dir)? {
+        let year = year
+            .parse()
+            .ok()
+            .filter(|&year| crate::year(year).is_some())
+            .ok_or_else(|| format!("`{}` is not named after a year", path.display()))?;
+        for (path, id) in subdirs(&path)? {
+            let day = crate::day(year, &id)
+                .ok_or_else(|| format!("`{}` is not named after a day", path.display()))?;
+            examples.extend(examples_of(day, &path)?);
+        }
+    }
+    examples.sort_by_key(|e| {
+        let day = crate::days().position(|d| std::ptr::eq(d, e.day));
+        (day, e.name.len(), e.name.clone())
+    });
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{self, Status};
+    use crate::examples::{discover, DIR};
+    use rayon::prelude::*;
+    use std::path::Path;
+
+    #[test]
+    fn examples() {
+        let examples = discover(&Path::new(env!("CARGO_MANIFEST_DIR")).join(DIR)).unwrap();
+        assert!(!examples.is_empty());
+        let failures = examples
+            .par_iter()
+            .flat_map_iter(|example| {
+                let name = example.path.display();
+                match example.check() {
+                    Err(e) => vec![e.to_string()],
+                    Ok(parts) => parts
+                        .into_iter()
+                        .filter_map(|(part, status)| match status {
+                            Status::Fail { expected, actual } => Some(format!(
+                                "{} part {}:\n{}",
+                                name,
+                                part,
+                                answers::diff(&expected, &actual)
+                            )),
+                            _ => None,
+                        })
+                        .collect(),
+                }
+            })
+            .collect::<Vec<_>>();
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn discovery() {
+        let dir = std::env::temp_dir().join(format!("advent-examples-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2021/12")).unwrap();
+        std::fs::write(dir.join("2021/12/ex1.txt"), "start-end\n").unwrap();
+        std::fs::write(dir.join("2021/12/ex10.txt"), "start-end\n").unwrap();
+        std::fs::write(dir.join("2021/12/ex2.txt"), "start-end\n").unwrap();
+        std::fs::write(
+            dir.join("2021/12/answers.txt"),
+            "ex1\t1\t1\nex2\t2\t1\nex10\t1\t1\n",
+        )
+        .unwrap();
+        let names = discover(&dir)
+            .unwrap()
+            .iter()
+            .map(|e| (e.name.clone(), e.part1.clone(), e.part2.clone()))
+            .collect::<Vec<_>>();
+        let one = Some("1".to_string());
+        assert_eq!(
+            vec![
+                ("ex1".to_string(), one.clone(), None),
+                ("ex2".to_string(), None, one.clone()),
+                ("ex10".to_string(), one, None),
+            ],
+            names
+        );
+
+        std::fs::write(dir.join("2021/12/ex3.txt"), "start-end\n").unwrap();
+        assert!(discover(&dir)
+            .err()
+            .unwrap()
+            .contains("ex3.txt` has no answers"));
+        std::fs::remove_file(dir.join("2021/12/ex3.txt")).unwrap();
+        std::fs::create_dir_all(dir.join("2021/26")).unwrap();
+        assert!(discover(&dir)
+            .err()
+            .unwrap()
+            .contains("is not named after a day"));
+        std::fs::remove_dir(dir.join("2021/26")).unwrap();
+        std::fs::create_dir_all(dir.join("1999")).unwrap();
+        assert!(discover(&dir)
+            .err()
+            .unwrap()
+            .contains("is not named after a year"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,267 @@
+//! Downloading puzzle inputs into a local cache. Requests go through `curl`,
+//! which handles TLS; the session token is passed to it on stdin so that it
+//! does not show up in the process list.
+
+use std::fmt;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Environment variables overriding the session token and the base URL.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Neither the environment variable nor the session file has a token.
+    NoSession(PathBuf),
+    /// `curl` could not be started.
+    NoCurl(std::io::Error),
+    /// `curl` could not reach the server.
+    Transport(String),
+    /// The server has no input at `url`, e.g. the puzzle is not unlocked.
+    NotFound(String),
+    /// The server rejected the session token.
+    BadSession(u16),
+    Status {
+        status: u16,
+        url: String,
+    },
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession(path) => write!(
+                f,
+                "no session token: set {} or put it in `{}`",
+                SESSION_VAR,
+                path.display()
+            ),
+            FetchError::NoCurl(e) => write!(f, "cannot run curl: {}", e),
+            FetchError::Transport(message) => write!(f, "download failed: {}", message),
+            FetchError::NotFound(url) => write!(
+                f,
+                "no input at {} (404), the puzzle may not be unlocked yet",
+                url
+            ),
+            FetchError::BadSession(status) => write!(
+                f,
+                "the session token was rejected ({}), it may have expired",
+                status
+            ),
+            FetchError::Status { status, url } => {
+                write!(f, "unexpected status {} from {}", status, url)
+            }
+            FetchError::Io { path, error } => {
+                write!(f, "cannot write `{}`: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// The session token from `env`, the value of [`SESSION_VAR`], or else from
+/// the first line of `file`.
+pub fn session(env: Option<String>, file: &Path) -> Result<String, FetchError> {
+    let token = env.or_else(|| {
+        let text = std::fs::read_to_string(file).ok()?;
+        text.lines().next().map(|line| line.to_string())
+    });
+    match token.map(|t| t.trim().to_string()) {
+        Some(token) if !token.is_empty() => Ok(token),
+        _ => Err(FetchError::NoSession(file.to_path_buf())),
+    }
+}
+
+pub fn input_url(base_url: &str, year: u32, day: u32) -> String {
+    format!(
+        "{}/{}/day/{}/input",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    )
+}
+
+/// Where a fetched input ended up.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already in the cache and was not downloaded again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of `day` of `year` into `<cache>/<year>/<day>.txt`,
+/// unless that file already exists. The download goes to a temporary file
+/// first, so a failed request never leaves a partial input behind.
+pub fn fetch(
+    base_url: &str,
+    session: &str,
+    year: u32,
+    day: u32,
+    cache: &Path,
+) -> Result<Fetched, FetchError> {
+    let dir = cache.join(year.to_string());
+    let path = dir.join(format!("{}.txt", day));
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+    let io = |path: &Path| {
+        let path = path.to_path_buf();
+        move |error| FetchError::Io { path, error }
+    };
+    std::fs::create_dir_all(&dir).map_err(io(&dir))?;
+
+    let url = input_url(base_url, year, day);
+    let partial = dir.join(format!(".{}.txt.part", day));
+    let mut curl = Command::new("curl")
+        .args(["--silent", "--show-error", "--header", "@-"])
+        .args(["--write-out", "%{http_code}", "--output"])
+        .arg(&partial)
+        .arg(&url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(FetchError::NoCurl)?;
+    if let Some(mut stdin) = curl.stdin.take() {
+        writeln!(stdin, "Cookie: session={}", session).map_err(FetchError::NoCurl)?;
+    }
+    let output = curl.wait_with_output().map_err(FetchError::NoCurl)?;
+    let status = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse::<u16>();
+
+    let res = match status {
+        _ if !output.status.success() => Err(FetchError::Transport(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        )),
+        Ok(200) => std::fs::rename(&partial, &path)
+            .map(|_| Fetched::Downloaded(path.clone()))
+            .map_err(io(&path)),
+        Ok(404) => Err(FetchError::NotFound(url)),
+        Ok(status @ 400) | Ok(status @ 401) | Ok(status @ 403) => {
+            Err(FetchError::BadSession(status))
+        }
+        Ok(status) => Err(FetchError::Status { status, url }),
+        Err(_) => Err(FetchError::Transport(format!(
+            "no status from curl for {}",
+            url
+        ))),
+    };
+    let _ = std::fs::remove_file(&partial);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::{fetch, input_url, session, FetchError, Fetched};
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::Path;
+    use std::process::Command;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Serves day 1 to the session `good`, rejects other sessions, and has
+    /// no other days. Returns the base URL and the number of requests served.
+    fn stub_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let served = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push(line);
+                }
+                served.fetch_add(1, Ordering::SeqCst);
+                let good = head.iter().any(|l| l.trim() == "Cookie: session=good");
+                let (status, body) = match (head[0].split(' ').nth(1), good) {
+                    (_, false) => ("400 Bad Request", "log in\n"),
+                    (Some("/2021/day/1/input"), true) => ("200 OK", "199\n200\n"),
+                    _ => ("404 Not Found", "not found\n"),
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (base_url, requests)
+    }
+
+    #[test]
+    fn urls() {
+        assert_eq!(
+            "https://adventofcode.com/2021/day/7/input",
+            input_url("https://adventofcode.com/", 2021, 7)
+        );
+        assert_eq!(
+            "abc",
+            session(Some(" abc\n".to_string()), Path::new("x")).unwrap()
+        );
+        assert!(matches!(
+            session(None, Path::new("/nonexistent/session")),
+            Err(FetchError::NoSession(_))
+        ));
+    }
+
+    #[test]
+    fn downloads() {
+        if Command::new("curl").arg("--version").output().is_err() {
+            eprintln!("skipping downloads: curl is not on the PATH");
+            return;
+        }
+        let (base_url, requests) = stub_server();
+        let cache = std::env::temp_dir().join(format!("advent-fetch-{}", std::process::id()));
+        let path = cache.join("2021/1.txt");
+
+        assert_eq!(
+            Fetched::Downloaded(path.clone()),
+            fetch(&base_url, "good", 2021, 1, &cache).unwrap()
+        );
+        assert_eq!("199\n200\n", std::fs::read_to_string(&path).unwrap());
+        assert_eq!(
+            Fetched::Cached(path.clone()),
+            fetch(&base_url, "good", 2021, 1, &cache).unwrap()
+        );
+        assert_eq!(1, requests.load(Ordering::SeqCst));
+
+        match fetch(&base_url, "good", 2021, 2, &cache) {
+            Err(FetchError::NotFound(url)) => assert!(url.ends_with("/2021/day/2/input")),
+            res => panic!("{:?}", res),
+        }
+        assert!(matches!(
+            fetch(&base_url, "bad", 2021, 3, &cache),
+            Err(FetchError::BadSession(400))
+        ));
+        assert!(!cache.join("2021/2.txt").exists());
+        assert_eq!(
+            vec![path],
+            std::fs::read_dir(cache.join("2021"))
+                .unwrap()
+                .map(|e| e.unwrap().path())
+                .collect::<Vec<_>>()
+        );
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+}
@@ -1,77 +1,215 @@
+use crate::parsing::{Line, ParseError};
+use crate::point::Point2;
+use std::convert::TryFrom;
 use std::fmt;
+use std::ops::{Index, IndexMut};
 
+/// The orthogonal steps in the order `nbors` has always yielded them: left,
+/// right, up, down.
+const NBORS: [Point2; 4] = [
+    Point2::new(-1, 0),
+    Point2::new(1, 0),
+    Point2::new(0, -1),
+    Point2::new(0, 1),
+];
+
+/// `NBORS` followed by the diagonal steps, left column first.
+const DIAG_NBORS: [Point2; 8] = [
+    Point2::new(-1, 0),
+    Point2::new(1, 0),
+    Point2::new(0, -1),
+    Point2::new(0, 1),
+    Point2::new(-1, -1),
+    Point2::new(-1, 1),
+    Point2::new(1, -1),
+    Point2::new(1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row in one buffer. `x` is
+/// the column and `y` the row, both counted from the top left.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Grid<Cell> {
-    pub elems: Vec<Vec<Cell>>,
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
 }
 
 #[allow(dead_code)]
 impl<Cell> Grid<Cell> {
-    pub fn new(elems: Vec<Vec<Cell>>) -> Grid<Cell> {
-        Grid { elems }
+    /// A grid of `rows`, which must all be the same length.
+    pub fn new(rows: Vec<Vec<Cell>>) -> Grid<Cell> {
+        Grid::try_new(rows)
+            .unwrap_or_else(|y| panic!("row {} of the grid is not as long as row 0", y))
+    }
+
+    /// A grid of `rows`, or the index of the first row whose length differs
+    /// from row 0.
+    pub fn try_new(rows: Vec<Vec<Cell>>) -> Result<Grid<Cell>, usize> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(y);
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
     }
 
     pub fn new_with<F: FnMut(usize, usize) -> Cell>(x: usize, y: usize, mut f: F) -> Grid<Cell> {
         Grid {
-            elems: (0..y).map(|y| (0..x).map(|x| f(x, y)).collect()).collect(),
+            width: x,
+            height: y,
+            cells: (0..y)
+                .flat_map(|y| (0..x).map(move |x| (x, y)))
+                .map(|(x, y)| f(x, y))
+                .collect(),
         }
     }
 
-    pub fn col_size(&self) -> usize {
-        self.elems.len()
+    pub fn width(&self) -> usize {
+        self.width
     }
 
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The length of a row, i.e. the width.
+    #[deprecated(note = "use `width`")]
     pub fn row_size(&self) -> usize {
-        self.elems.get(0).map(|row| row.len()).unwrap_or(0)
+        self.width
+    }
+
+    /// The length of a column, i.e. the height.
+    #[deprecated(note = "use `height`")]
+    pub fn col_size(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
     }
 
     pub fn get(&self, x: usize, y: usize) -> Option<&Cell> {
-        self.elems
-            .get(y as usize)
-            .and_then(|col| col.get(x as usize))
+        self.index_of(x, y).map(|i| &self.cells[i])
     }
 
-    pub fn set(&mut self, x: usize, y: usize, mut new: Cell) -> Option<Cell> {
-        self.elems.get_mut(y as usize).and_then(|col| {
-            let prev = col.get_mut(x as usize)?;
-            std::mem::swap(prev, &mut new);
-            Some(new)
-        })
+    /// The cell at `p`, or `None` outside the grid.
+    pub fn get_point(&self, p: Point2) -> Option<&Cell> {
+        let (x, y) = <(usize, usize)>::try_from(p).ok()?;
+        self.get(x, y)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Cell> {
+        let i = self.index_of(x, y)?;
+        Some(&mut self.cells[i])
+    }
+
+    /// Replaces the cell at `x`, `y`, returning the previous one, or `None`
+    /// outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, new: Cell) -> Option<Cell> {
+        self.get_mut(x, y).map(|prev| std::mem::replace(prev, new))
+    }
+
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
     }
 
     pub fn legal(&self, x: i32, y: i32) -> bool {
-        y >= 0 && y < self.col_size() as i32 && x >= 0 && x < self.row_size() as i32
-    }
-
-    pub fn nbors(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-        let x = x as i32;
-        let y = y as i32;
-        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
-            .iter()
-            .copied()
-            .filter(|(x, y)| self.legal(*x, *y))
-            .map(|(x, y)| (x as usize, y as usize))
-            .collect()
-    }
-
-    pub fn diag_nbors(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-        let x = x as i32;
-        let y = y as i32;
-        [
-            (x - 1, y),
-            (x + 1, y),
-            (x, y - 1),
-            (x, y + 1),
-            (x - 1, y - 1),
-            (x - 1, y + 1),
-            (x + 1, y - 1),
-            (x + 1, y + 1),
-        ]
-        .iter()
-        .copied()
-        .filter(|(x, y)| self.legal(*x, *y))
-        .map(|(x, y)| (x as usize, y as usize))
-        .collect()
+        y >= 0 && y < self.height as i32 && x >= 0 && x < self.width as i32
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        self.legal(p.x, p.y)
+    }
+
+    /// The orthogonal neighbors of `x`, `y` inside the grid: left, right,
+    /// up, down. Like the other position iterators this does not borrow the
+    /// grid, so the cells can be changed while iterating.
+    pub fn nbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offset_nbors(x, y, &NBORS)
+    }
+
+    /// The orthogonal neighbors of `x`, `y` inside the grid as in `nbors`,
+    /// then the diagonal ones.
+    pub fn diag_nbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offset_nbors(x, y, &DIAG_NBORS)
+    }
+
+    /// `x`, `y` moved by each of `offsets`, skipping positions outside the
+    /// grid.
+    pub fn offset_nbors<'o>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'o [Point2],
+    ) -> impl Iterator<Item = (usize, usize)> + 'o {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |d| {
+            let x = x.checked_add_signed(d.x as isize).filter(|&x| x < width)?;
+            let y = y.checked_add_signed(d.y as isize).filter(|&y| y < height)?;
+            Some((x, y))
+        })
+    }
+
+    /// `offsets`, or none of them if the grid has no cells.
+    fn unless_empty<'o>(&self, offsets: &'o [Point2]) -> &'o [Point2] {
+        if self.width == 0 || self.height == 0 {
+            &offsets[..0]
+        } else {
+            offsets
+        }
+    }
+
+    /// `x`, `y` moved by each of `offsets` on a grid whose opposite edges
+    /// are joined, so that every offset yields a position. An empty grid has
+    /// no positions to yield.
+    pub fn wrapping_nbors<'o>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'o [Point2],
+    ) -> impl Iterator<Item = (usize, usize)> + 'o {
+        let size = Point2::from((self.width, self.height));
+        let p = Point2::from((x, y));
+        self.unless_empty(offsets).iter().map(move |&d| {
+            let q = (p + d).wrap(size);
+            (q.x as usize, q.y as usize)
+        })
+    }
+
+    /// `x`, `y` moved by each of `offsets`, with positions outside the grid
+    /// moved to the nearest edge. The same position may come up repeatedly,
+    /// and an empty grid yields none.
+    pub fn clamped_nbors<'o>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'o [Point2],
+    ) -> impl Iterator<Item = (usize, usize)> + 'o {
+        let max = Point2::from((self.width, self.height)) - Point2::new(1, 1);
+        let p = Point2::from((x, y));
+        self.unless_empty(offsets).iter().map(move |&d| {
+            let q = p + d;
+            (q.x.clamp(0, max.x) as usize, q.y.clamp(0, max.y) as usize)
+        })
+    }
+
+    /// The cells around `x`, `y` at `offsets`, with their positions.
+    pub fn nbor_cells<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [Point2],
+    ) -> impl Iterator<Item = (usize, usize, &'a Cell)> {
+        self.offset_nbors(x, y, offsets)
+            .map(move |(x, y)| (x, y, &self.cells[y * self.width + x]))
     }
 
     pub fn ray<'a>(
@@ -89,7 +227,7 @@ impl<Cell> Grid<Cell> {
             return None;
         }
 
-        while self.legal(x, y) && cont(&self.elems[y as usize][x as usize]) {
+        while self.legal(x, y) && cont(&self[(x as usize, y as usize)]) {
             x += dx;
             y += dy;
         }
@@ -101,46 +239,137 @@ impl<Cell> Grid<Cell> {
         }
     }
 
-    pub fn map<T, F: FnMut(&Cell) -> T>(&self, mut f: F) -> Grid<T> {
-        Grid::new_with(self.row_size(), self.col_size(), |x, y| {
-            f(self.get(x, y).unwrap())
-        })
+    pub fn map<T, F: FnMut(&Cell) -> T>(&self, f: F) -> Grid<T> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
     }
 
+    /// The cells in reading order.
     pub fn iter(&self) -> impl Iterator<Item = &Cell> {
-        (0..self.col_size())
-            .flat_map(move |y| (0..self.row_size()).map(move |x| (x, y)))
-            .map(move |(x, y)| self.get(x, y).unwrap())
+        self.cells.iter()
     }
 }
 
-impl<Cell: fmt::Display> fmt::Display for Grid<Cell> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut rows = 10;
-        for row in &self.elems {
-            let mut cols = 10;
-            for cell in row {
-                cols -= 1;
-                write!(f, "{}", cell)?;
-                if cols == 0 {
+impl<Cell> Index<(usize, usize)> for Grid<Cell> {
+    type Output = Cell;
+
+    /// The cell at `(x, y)`; panics outside the grid.
+    fn index(&self, (x, y): (usize, usize)) -> &Cell {
+        match self.index_of(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "({}, {}) is outside the {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<Cell> IndexMut<(usize, usize)> for Grid<Cell> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Cell {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside the {}x{} grid", x, y, width, height),
+        }
+    }
+}
+
+impl<Cell> Grid<Cell> {
+    /// Parses one row per line and one cell per character with `f`, which
+    /// returns `None` for characters that are not `expected`. Every row must
+    /// be as long as the first.
+    pub fn parse_chars<'a, I, F>(lines: I, expected: &str, f: F) -> Result<Grid<Cell>, ParseError>
+    where
+        I: IntoIterator<Item = Line<'a>>,
+        F: Fn(char) -> Option<Cell>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines {
+            let row = line.chars(expected, &f)?;
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                let offset = line
+                    .text
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.text.len(), |(i, _)| i);
+                let expected = format!("a row of {} cells like the first", width);
+                return Err(line.error_at_offset(offset, &expected));
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Writes the rows one per line. The alternate flag, `{:#}`, adds a `|`
+    /// after every 10 columns and a `-` line after every 10 rows to help
+    /// find positions in large grids.
+    fn write_rows<W>(&self, f: &mut fmt::Formatter<'_>, mut write_cell: W) -> fmt::Result
+    where
+        W: FnMut(&mut fmt::Formatter<'_>, &Cell) -> fmt::Result,
+    {
+        let ruled = f.alternate();
+        for (y, row) in self.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                write_cell(f, cell)?;
+                if ruled && (x + 1) % 10 == 0 {
                     write!(f, "|")?;
-                    cols = 10;
                 }
             }
-            rows -= 1;
             writeln!(f)?;
-            if rows == 0 {
+            if ruled && (y + 1) % 10 == 0 {
                 writeln!(f, "-")?;
-                rows = 10;
             }
         }
-        write!(f, "")
+        Ok(())
+    }
+
+    /// Displays the grid with each cell drawn as the character `f` maps it
+    /// to, so that a grid parsed by `parse_chars` can be written back.
+    pub fn display_with<F: Fn(&Cell) -> char>(&self, f: F) -> DisplayWith<'_, Cell, F> {
+        DisplayWith { grid: self, f }
+    }
+
+    pub fn to_string_with<F: Fn(&Cell) -> char>(&self, f: F) -> String {
+        self.display_with(f).to_string()
+    }
+}
+
+/// A grid drawn with a cell-to-character mapping, see [`Grid::display_with`].
+pub struct DisplayWith<'a, Cell, F> {
+    grid: &'a Grid<Cell>,
+    f: F,
+}
+
+impl<Cell, F: Fn(&Cell) -> char> fmt::Display for DisplayWith<'_, Cell, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid
+            .write_rows(f, |f, cell| write!(f, "{}", (self.f)(cell)))
+    }
+}
+
+impl<Cell: fmt::Display> fmt::Display for Grid<Cell> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_rows(f, |f, cell| write!(f, "{}", cell))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::grid::*;
+    use crate::parsing::lines;
+    use crate::point::Point2;
 
     #[test]
     fn ray() {
@@ -162,4 +391,138 @@ mod tests {
         assert_eq!(Some(&0), grid.ray(-1, 4, 1, -1, |n| *n == 1));
         assert_eq!(Some(&0), grid.ray(-1, 1, 1, 0, |n| *n == 1));
     }
+
+    #[test]
+    fn storage() {
+        let mut grid = Grid::new_with(3, 2, |x, y| x + 10 * y);
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        #[allow(deprecated)]
+        let sizes = (grid.row_size(), grid.col_size());
+        assert_eq!((3, 2), sizes);
+        assert_eq!(12, grid[(2, 1)]);
+        assert_eq!(Some(&10), grid.get(0, 1));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(None, grid.get(0, 2));
+        assert_eq!(Some(12), grid.set(2, 1, 7));
+        assert_eq!(None, grid.set(3, 1, 7));
+        assert_eq!(vec![(1, 1), (0, 0)], grid.nbors(0, 1).collect::<Vec<_>>());
+        grid[(0, 0)] = 5;
+        assert_eq!(
+            vec![&[5, 1, 2][..], &[10, 11, 7][..]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(grid, Grid::new(vec![vec![5, 1, 2], vec![10, 11, 7]]));
+        assert_eq!(
+            vec![6, 2, 3, 11, 12, 8],
+            grid.map(|c| c + 1).iter().copied().collect::<Vec<_>>()
+        );
+
+        assert_eq!(Err(2), Grid::try_new(vec![vec![1, 2], vec![3, 4], vec![5]]));
+        assert_eq!(0, Grid::<u8>::new(vec![]).width());
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new_with(3, 3, |x, y| x + 3 * y);
+        assert_eq!(
+            vec![(1, 1), (0, 0), (0, 2)],
+            grid.nbors(0, 1).collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.diag_nbors(1, 1).count());
+        assert_eq!(
+            vec![(1, 2), (2, 1), (1, 1)],
+            grid.diag_nbors(2, 2).collect::<Vec<_>>()
+        );
+        let knight = [Point2::new(1, 2), Point2::new(2, -1), Point2::new(-2, 1)];
+        assert_eq!(
+            vec![(2, 0)],
+            grid.offset_nbors(0, 1, &knight).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0, &1), (0, 1, &3)],
+            grid.nbor_cells(0, 0, &Point2::ORTHOGONAL)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 2), (2, 0), (1, 0), (0, 1)],
+            grid.wrapping_nbors(0, 0, &Point2::ORTHOGONAL)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 0), (0, 0), (1, 0), (0, 1)],
+            grid.clamped_nbors(0, 0, &Point2::ORTHOGONAL)
+                .collect::<Vec<_>>()
+        );
+
+        let empty = Grid::<u8>::new_with(0, 3, |_, _| 0);
+        assert_eq!(0, empty.wrapping_nbors(0, 0, &Point2::ADJACENT).count());
+        assert_eq!(0, empty.clamped_nbors(0, 0, &Point2::ADJACENT).count());
+        let empty = Grid::<u8>::new(vec![]);
+        assert_eq!(0, empty.wrapping_nbors(1, 1, &Point2::ORTHOGONAL).count());
+        assert_eq!(0, empty.clamped_nbors(1, 1, &Point2::ORTHOGONAL).count());
+
+        let mut grid = grid;
+        for (x, y) in grid.diag_nbors(1, 1) {
+            grid[(x, y)] = 0;
+        }
+        assert_eq!(4, grid.iter().sum::<usize>());
+    }
+
+    #[test]
+    fn text() {
+        let input = ["#..", ".#.", "..#"];
+        let grid = Grid::parse_chars(lines(&input), "# or .", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!(Some(&true), grid.get(1, 1));
+        let text = grid.to_string_with(|&c| if c { '#' } else { '.' });
+        assert_eq!("#..\n.#.\n..#\n", text);
+        assert_eq!(input, text.lines().collect::<Vec<_>>()[..]);
+        assert_eq!("100\n010\n001\n", grid.map(|&c| c as u8).to_string());
+
+        let err = Grid::parse_chars(lines(&["12", "3x"]), "a digit", |c| c.to_digit(10));
+        assert_eq!((2, 2), err.map_err(|e| (e.line, e.column)).unwrap_err());
+        let err = Grid::parse_chars(lines(&["12", "345"]), "a digit", |c| c.to_digit(10));
+        let err = err.unwrap_err();
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!("a row of 2 cells like the first", err.expected);
+        let err = Grid::parse_chars(lines(&["12", "3"]), "a digit", |c| c.to_digit(10));
+        assert_eq!((2, 2), err.map_err(|e| (e.line, e.column)).unwrap_err());
+        let empty = Grid::parse_chars(lines::<&str>(&[]), "a digit", |c| c.to_digit(10));
+        assert_eq!(Ok((0, 0)), empty.map(|g| (g.width(), g.height())));
+    }
+
+    #[test]
+    fn rulers() {
+        let grid = Grid::new_with(12, 11, |x, y| (x + y) % 10);
+        let ruled = format!("{:#}", grid);
+        let rows = ruled.lines().collect::<Vec<_>>();
+        assert_eq!(12, rows.len());
+        assert_eq!("0123456789|01", rows[0]);
+        assert_eq!("-", rows[10]);
+        assert_eq!("1234567890|12", rows[1]);
+        assert_eq!("0123456789|01", rows[11]);
+        assert!(!grid.to_string().contains('|'));
+        assert!(format!("{:#}", grid.display_with(|_| '.')).contains("..........|..\n"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn outside() {
+        let _ = Grid::new(vec![vec![1, 2]])[(0, 1)];
+    }
+
+    #[test]
+    fn points() {
+        let grid = Grid::new(vec![vec![0, 1], vec![2, 3]]);
+        assert_eq!(Some(&2), grid.get_point(Point2::new(0, 1)));
+        assert_eq!(None, grid.get_point(Point2::new(-1, 1)));
+        assert_eq!(None, grid.get_point(Point2::new(2, 1)));
+        assert!(grid.contains(Point2::new(1, 1)));
+        assert!(!grid.contains(Point2::new(1, -1)));
+    }
 }
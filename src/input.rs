@@ -0,0 +1,102 @@
+use std::fmt;
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+/// The input directory, relative to the crate root.
+pub const DIR: &str = "inputs";
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// `inputs/<year>/N.txt` in the working directory.
+    Default,
+    /// One file, used for every selected day.
+    File(PathBuf),
+    Stdin,
+    /// A directory holding a directory per year with one `N.txt` per day,
+    /// laid out like `inputs`.
+    Dir(PathBuf),
+}
+
+impl Source {
+    /// The file a day is read from, or `None` for stdin.
+    pub fn path(&self, year: u32, day: u32) -> Option<PathBuf> {
+        let in_dir = |dir: &Path| dir.join(year.to_string()).join(format!("{}.txt", day));
+        match self {
+            Source::Default => Some(in_dir(Path::new(DIR))),
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+            Source::Dir(dir) => Some(in_dir(dir)),
+        }
+    }
+
+    /// Whether the source can only be read by a single day.
+    pub fn is_single(&self) -> bool {
+        matches!(self, Source::File(_) | Source::Stdin)
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub path: Option<PathBuf>,
+    pub error: std::io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "cannot read input `{}`: {}", path.display(), self.error),
+            None => write!(f, "cannot read input from stdin: {}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+fn read_lines<R: BufRead>(reader: R) -> std::io::Result<Vec<String>> {
+    reader.lines().collect()
+}
+
+fn read_file(path: &Path) -> std::io::Result<Vec<String>> {
+    read_lines(BufReader::new(File::open(path)?))
+}
+
+/// Reads the input lines of `day` of `year` from `source`.
+pub fn load(source: &Source, year: u32, day: u32) -> Result<Vec<String>, InputError> {
+    let path = source.path(year, day);
+    let lines = match &path {
+        Some(path) => read_file(path),
+        None => read_lines(std::io::stdin().lock()),
+    };
+    lines.map_err(|error| InputError { path, error })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{load, Source};
+    use std::path::PathBuf;
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            Some(PathBuf::from("inputs/2021/15.txt")),
+            Source::Default.path(2021, 15)
+        );
+        assert_eq!(
+            Some(PathBuf::from("cache/2020/15.txt")),
+            Source::Dir(PathBuf::from("cache")).path(2020, 15)
+        );
+        assert_eq!(None, Source::Stdin.path(2021, 15));
+    }
+
+    #[test]
+    fn missing_file() {
+        let source = Source::Dir(PathBuf::from("no/such/dir"));
+        let err = load(&source, 2021, 3).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("cannot read input `no/such/dir/2021/3.txt`: "));
+    }
+}
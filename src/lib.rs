@@ -0,0 +1,50 @@
+//! Advent of Code solutions. Every year has its own module, e.g. `y2021`,
+//! whose days expose their `parse` and solve functions along with a
+//! `Solution` impl. Each year's `DAYS` registers its days, and `YEARS`
+//! registers the years for the `advent` binary. Shared code such as `grid`,
+//! `point` and `utils` lives at the top level.
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod examples;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+#[macro_use]
+pub mod log;
+pub mod memory;
+pub mod parsing;
+pub mod point;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod sparse_grid;
+#[macro_use]
+pub mod utils;
+pub mod watch;
+
+pub mod y2021;
+
+use solution::{Day, Year};
+
+/// Every year, oldest first.
+pub const YEARS: &[Year] = &[Year {
+    year: 2021,
+    days: y2021::DAYS,
+}];
+
+pub fn year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// Looks up a solver by its year and command line id, e.g. `15` or `24b`.
+pub fn day(year: u32, id: &str) -> Option<&'static Day> {
+    self::year(year)?.days.iter().find(|day| day.id == id)
+}
+
+/// Every solver of every year, in run order.
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|year| year.days)
+}
@@ -0,0 +1,241 @@
+//! Leveled logging for solver diagnostics. Events go to stderr when the
+//! global [`Filter`] lets them through, and the arguments of a filtered
+//! event are never evaluated, so a day can trace a whole grid per step
+//! without slowing down a normal run.
+//!
+//! A filter is written like `RUST_LOG`: comma-separated directives, each
+//! either a level that applies everywhere or `module=level` for one module,
+//! e.g. `info,day_25=trace`. A module without a level gets `trace`, and the
+//! most specific directive for a module wins.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+const LEVELS: [Level; 5] = [
+    Level::Error,
+    Level::Warn,
+    Level::Info,
+    Level::Debug,
+    Level::Trace,
+];
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// The most verbose level let through, `None` for none at all.
+fn parse_level(name: &str) -> Result<Option<Level>, String> {
+    match name.to_ascii_lowercase().as_str() {
+        "off" => Ok(None),
+        "error" => Ok(Some(Level::Error)),
+        "warn" => Ok(Some(Level::Warn)),
+        "info" => Ok(Some(Level::Info)),
+        "debug" => Ok(Some(Level::Debug)),
+        "trace" => Ok(Some(Level::Trace)),
+        _ => Err(format!("unknown log level `{}`", name)),
+    }
+}
+
+/// Which events are written, by module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    /// Module paths relative to the crate, e.g. `day_25`, and their levels.
+    modules: Vec<(String, Option<Level>)>,
+}
+
+impl Default for Filter {
+    /// Errors and warnings only.
+    fn default() -> Filter {
+        Filter {
+            default: Some(Level::Warn),
+            modules: Vec::new(),
+        }
+    }
+}
+
+impl Filter {
+    pub fn parse(spec: &str) -> Result<Filter, String> {
+        let mut filter = Filter::default();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) if !module.is_empty() => filter
+                    .modules
+                    .push((module.to_string(), parse_level(level)?)),
+                Some(_) => return Err(format!("missing module in `{}`", directive)),
+                None => match parse_level(directive) {
+                    Ok(level) => filter.default = level,
+                    Err(_) => filter
+                        .modules
+                        .push((directive.to_string(), Some(Level::Trace))),
+                },
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Raises the default level by `steps`, as given by repeated `-v`s.
+    pub fn verbose(mut self, steps: u8) -> Filter {
+        let current = self.default.map_or(0, |l| l as usize);
+        let raised = (current + steps as usize).min(LEVELS.len());
+        if raised > 0 {
+            self.default = Some(LEVELS[raised - 1]);
+        }
+        self
+    }
+
+    fn level(&self, module: &str) -> Option<Level> {
+        self.modules
+            .iter()
+            .filter(|(name, _)| contains_path(module, name))
+            .max_by_key(|(name, _)| name.len())
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    /// Whether an event at `level` from `module`, a path relative to the
+    /// crate root, is written.
+    pub fn enabled(&self, module: &str, level: Level) -> bool {
+        self.level(module).is_some_and(|max| level <= max)
+    }
+
+    fn max_level(&self) -> Option<Level> {
+        let modules = self.modules.iter().map(|&(_, level)| level);
+        modules.chain(std::iter::once(self.default)).max().flatten()
+    }
+}
+
+/// Whether the segments of `name` appear in a row in the path `module`, so
+/// that `day_2` matches `day_2` and `day_2::tests` but not `day_24`.
+fn contains_path(module: &str, name: &str) -> bool {
+    let module = module.split("::").collect::<Vec<_>>();
+    let name = name.split("::").collect::<Vec<_>>();
+    module.windows(name.len()).any(|w| w == &name[..])
+}
+
+static FILTER: RwLock<Option<Filter>> = RwLock::new(None);
+/// The most verbose level of any module, checked before taking the lock.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Replaces the global filter.
+pub fn init(filter: Filter) {
+    let max = filter.max_level().map_or(0, |l| l as u8);
+    *FILTER.write().unwrap_or_else(|e| e.into_inner()) = Some(filter);
+    MAX_LEVEL.store(max, Ordering::Relaxed);
+}
+
+/// Strips the crate name off a `module_path!()`.
+fn relative(module_path: &str) -> &str {
+    module_path
+        .split_once("::")
+        .map_or(module_path, |(_, module)| module)
+}
+
+/// Whether the global filter writes an event at `level` from `module_path`.
+pub fn enabled(module_path: &str, level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    let filter = FILTER.read().unwrap_or_else(|e| e.into_inner());
+    match &*filter {
+        Some(filter) => filter.enabled(relative(module_path), level),
+        None => Filter::default().enabled(relative(module_path), level),
+    }
+}
+
+pub fn write(module_path: &str, level: Level, args: fmt::Arguments) {
+    eprintln!("{:<5} {}: {}", level, relative(module_path), args);
+}
+
+/// Writes an event at a [`Level`] if the global filter lets it through.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        if $crate::log::enabled(module_path!(), level) {
+            $crate::log::write(module_path!(), level, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::log::{Filter, Level};
+
+    #[test]
+    fn filters() {
+        let filter = Filter::default();
+        assert!(filter.enabled("day_25", Level::Warn));
+        assert!(!filter.enabled("day_25", Level::Info));
+
+        let filter = Filter::parse("info,day_25=trace,day_2=off").unwrap();
+        assert!(filter.enabled("day_25", Level::Trace));
+        assert!(filter.enabled("day_24", Level::Info));
+        assert!(!filter.enabled("day_24", Level::Debug));
+        assert!(!filter.enabled("day_2", Level::Error));
+        assert!(!filter.enabled("day_2::tests", Level::Error));
+
+        let filter = Filter::parse("day_24b, day_24b::alu=info").unwrap();
+        assert!(filter.enabled("day_24b", Level::Trace));
+        assert!(!filter.enabled("day_24b::alu", Level::Debug));
+        assert!(!filter.enabled("day_24", Level::Info));
+
+        assert!(Filter::parse("day_1=loud").is_err());
+        assert!(Filter::parse("=info").is_err());
+    }
+
+    #[test]
+    fn verbosity() {
+        let filter = Filter::default().verbose(1);
+        assert!(filter.enabled("day_1", Level::Info));
+        assert!(!filter.enabled("day_1", Level::Debug));
+        assert!(Filter::default().verbose(7).enabled("day_1", Level::Trace));
+        let filter = Filter::parse("off").unwrap().verbose(2);
+        assert!(filter.enabled("day_1", Level::Warn));
+        assert!(!filter.enabled("day_1", Level::Info));
+        assert_eq!(Filter::default(), Filter::default().verbose(0));
+    }
+}
@@ -1,61 +1,447 @@
-mod grid;
-#[macro_use]
-mod utils;
-
-mod day_1;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_2;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24b;
-mod day_25;
-mod day_3;
-mod day_4;
-mod day_5;
-mod day_6;
-mod day_7;
-mod day_8;
-mod day_9;
+mod cli;
+
+use advent::answer::Answer;
+use advent::answers::{self, Answers, Status};
+use advent::bench::{self, Baseline};
+use advent::examples::{self, Example};
+use advent::fetch::{self, Fetched};
+use advent::input::{self, Source};
+use advent::log::{self, Filter};
+use advent::memory::{self, Counting};
+use advent::parsing::ParseError;
+use advent::runner::{self, DayResult, Outcome};
+use advent::scaffold;
+use advent::solution::Day;
+use advent::watch::{Change, History, Watch};
+use cli::{BenchOptions, Command, Format, RunOptions};
+use std::io::IsTerminal;
+use std::path::Path;
+use std::time::Duration;
+
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+fn print_answer(day: &Day, part: u8, answer: &Answer) {
+    match answer {
+        // Grids start on their own line
+        Answer::Grid(_) => println!("Day {} part {}:{}", day.id, part, answer),
+        _ => println!("Day {} part {}: {}", day.id, part, answer),
+    }
+}
+
+fn print_outcome(day: &Day, part: u8, outcome: &Outcome) {
+    match outcome {
+        Outcome::Ok(answer) => print_answer(day, part, answer),
+        _ => println!("Day {} part {}: {}", day.id, part, outcome),
+    }
+}
+
+fn print_result(result: &DayResult) {
+    for (part, p) in result.parts() {
+        print_outcome(result.day, part, &p.outcome);
+    }
+}
+
+/// Prints the diagnostic of an input of `day` that did not parse.
+fn print_diagnostic(options: &RunOptions, day: &Day, error: &ParseError) {
+    let path = match options.input.path(day.year, day.number) {
+        Some(path) => path.display().to_string(),
+        None => "<stdin>".to_string(),
+    };
+    eprint!("{}", error.diagnostic(&path));
+}
+
+/// Prints the diagnostic of a day whose input did not parse.
+fn print_parse_error(options: &RunOptions, result: &DayResult) {
+    let error = result.parts().find_map(|(_, p)| match &p.outcome {
+        Outcome::Invalid(error) => Some(error),
+        _ => None,
+    });
+    if let Some(error) = error {
+        print_diagnostic(options, result.day, error);
+    }
+}
+
+/// Sets up logging from --log, or else $RUST_LOG, raised by -v.
+fn init_log(options: &RunOptions) {
+    let filter = options.log.clone().unwrap_or_else(|| {
+        match std::env::var("RUST_LOG").map(|spec| Filter::parse(&spec)) {
+            Ok(Ok(filter)) => filter,
+            Ok(Err(e)) => {
+                eprintln!("warning: ignoring $RUST_LOG: {}", e);
+                Filter::default()
+            }
+            Err(_) => Filter::default(),
+        }
+    });
+    log::init(filter.verbose(options.verbose));
+}
+
+/// Runs the selected days, calling `each` as every day finishes.
+fn solve<F: FnMut(&DayResult)>(options: &RunOptions, mut each: F) -> Vec<DayResult> {
+    init_log(options);
+    if options.alloc_stats && !memory::enable() {
+        eprintln!("error: cannot count allocations");
+        std::process::exit(1);
+    }
+    let mut results = Vec::new();
+    for &(year, id) in &options.days {
+        match runner::run(
+            advent::day(year, id).unwrap(),
+            options.parts,
+            &options.input,
+            options.timeout,
+        ) {
+            Ok(result) => {
+                if options.format == Format::Text {
+                    print_parse_error(options, &result);
+                }
+                each(&result);
+                results.push(result);
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if options.time && options.format == Format::Text {
+        print!("\n{}", runner::timing_table(&results));
+    }
+    if options.alloc_stats && options.format == Format::Text {
+        print!("\n{}", runner::alloc_table(&results));
+    }
+    results
+}
+
+fn load_answers(path: &Path) -> Answers {
+    Answers::load(path).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    })
+}
+
+/// Runs the selected days and reports every part that failed on stderr.
+/// Exits with an error if any part panicked, timed out or had invalid input.
+fn run(options: RunOptions) {
+    let results = match options.format {
+        Format::Text => solve(&options, print_result),
+        Format::Json => {
+            let results = solve(&options, |_| {});
+            print!("{}", runner::to_json(&results));
+            results
+        }
+    };
+
+    if let Some(report) = runner::failure_report(&results) {
+        eprint!("\n{}", report);
+    }
+    let failed = results.iter().flat_map(|r| r.parts()).any(|(_, p)| {
+        matches!(
+            p.outcome,
+            Outcome::Panic { .. } | Outcome::Timeout | Outcome::Invalid(_)
+        )
+    });
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn verify(options: RunOptions, path: &Path) {
+    let answers = load_answers(path);
+    let (mut failed, mut missing) = (0, 0);
+    solve(&options, |result| {
+        for (part, p) in result.parts() {
+            let (key, outcome) = (result.day.key(), &p.outcome);
+            let answer = match outcome {
+                Outcome::Ok(answer) => answer,
+                Outcome::Panic { .. } | Outcome::Timeout | Outcome::Invalid(_) => {
+                    failed += 1;
+                    print_outcome(result.day, part, outcome);
+                    continue;
+                }
+                Outcome::Unimplemented => {
+                    missing += 1;
+                    print_outcome(result.day, part, outcome);
+                    continue;
+                }
+            };
+            match answers.check(&key, part, answer) {
+                Status::Pass => println!("Day {} part {}: ok", result.day.id, part),
+                Status::Missing => {
+                    missing += 1;
+                    println!("Day {} part {}: no recorded answer", result.day.id, part);
+                }
+                Status::Fail { expected, actual } => {
+                    failed += 1;
+                    println!("Day {} part {}: FAILED", result.day.id, part);
+                    print!("{}", answers::diff(&expected, &actual));
+                }
+            }
+        }
+    });
+
+    println!(
+        "\n{} failed, {} without a recorded answer or not implemented",
+        failed, missing
+    );
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn record(options: RunOptions, path: &Path) {
+    let mut answers = load_answers(path);
+    solve(&options, |result| {
+        print_result(result);
+        for (part, p) in result.parts() {
+            if let Some(answer) = p.outcome.answer() {
+                answers.set(&result.day.key(), part, answers::text(answer));
+            }
+        }
+    });
+    answers.sort_by_day(|key| advent::days().position(|day| day.key() == key));
+    if let Err(e) = answers.save(path) {
+        eprintln!("error: cannot write `{}`: {}", path.display(), e);
+        std::process::exit(1);
+    }
+}
+
+/// Times every selected part and compares the medians against the baseline.
+/// Exits with an error if a part regressed or failed to produce an answer.
+fn bench(options: RunOptions, bench: BenchOptions) {
+    let mut baseline = Baseline::load(&bench.baseline).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    });
+    init_log(&options);
+    let (mut regressions, mut failed) = (0, 0);
+    print!("{}", bench::table_header());
+    for &(year, id) in &options.days {
+        let day = advent::day(year, id).unwrap();
+        let lines = input::load(&options.input, day.year, day.number).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        });
+        let parts = [(1, options.parts.one()), (2, options.parts.two())];
+        for &(part, _) in parts.iter().filter(|(_, selected)| *selected) {
+            let stats = match bench::bench(day, part, &lines, bench.warmup, bench.runs) {
+                Ok(stats) => stats,
+                Err(outcome) => {
+                    failed += 1;
+                    println!("{:>4} {:>4} {}", day.id, part, outcome);
+                    if let Outcome::Invalid(error) = &outcome {
+                        // The other part would fail on the same input
+                        print_diagnostic(&options, day, error);
+                        break;
+                    }
+                    continue;
+                }
+            };
+            let previous = baseline.get(&day.key(), part);
+            print!(
+                "{}",
+                bench::table_row(day.id, part, &stats, previous, bench.threshold)
+            );
+            if previous.is_some_and(|p| bench::is_regression(p, stats.median, bench.threshold)) {
+                regressions += 1;
+            }
+            baseline.set(&day.key(), part, stats.median);
+        }
+    }
+
+    if bench.save {
+        baseline.sort_by_day(|key| advent::days().position(|day| day.key() == key));
+        if let Err(e) = baseline.save(&bench.baseline) {
+            eprintln!("error: cannot write `{}`: {}", bench.baseline.display(), e);
+            std::process::exit(1);
+        }
+    }
+    if regressions > 0 || failed > 0 {
+        eprintln!(
+            "\n{} regression(s) beyond {}%, {} part(s) failed",
+            regressions, bench.threshold, failed
+        );
+        std::process::exit(1);
+    }
+}
+
+/// Prints `label` and the result `shown` next to it, with the previous
+/// result if it changed, highlighted on a terminal.
+fn print_watched(label: &str, shown: &str, change: &Change) {
+    let line = match change {
+        Change::Changed(previous) => format!("{}:{} (was {})", label, shown, previous),
+        _ => format!("{}:{}", label, shown),
+    };
+    match change {
+        Change::Changed(_) if std::io::stdout().is_terminal() => {
+            println!("\x1b[1;33m{}\x1b[0m", line)
+        }
+        _ => println!("{}", line),
+    }
+}
+
+/// Solves the watched day and its examples, comparing every answer to the
+/// previous run in `history`.
+fn watch_once(options: &RunOptions, day: &'static Day, history: &mut History) {
+    match runner::run(day, options.parts, &options.input, options.timeout) {
+        Ok(result) => {
+            print_parse_error(options, &result);
+            for (part, p) in result.parts() {
+                let text = match p.outcome.answer() {
+                    Some(answer) => answers::text(answer),
+                    None => p.outcome.to_string(),
+                };
+                let change = history.update(&format!("part {}", part), &text);
+                let shown = match p.outcome.answer() {
+                    // Grids start on their own line
+                    Some(answer @ Answer::Grid(_)) => answer.to_string(),
+                    _ => format!(" {}", p.outcome),
+                };
+                let label = format!(
+                    "Day {} part {} ({})",
+                    day.id,
+                    part,
+                    runner::format_duration(p.time)
+                );
+                print_watched(&label, &shown, &change);
+            }
+        }
+        Err(e) => println!("error: {}", e),
+    }
+
+    let examples = match examples::discover(Path::new(examples::DIR)) {
+        Ok(examples) => examples,
+        Err(e) => return println!("error: {}", e),
+    };
+    for example in examples.iter().filter(|e| std::ptr::eq(e.day, day)) {
+        watch_example(example, history);
+    }
+}
+
+fn watch_example(example: &Example, history: &mut History) {
+    let parts = match example.check() {
+        Ok(parts) => parts,
+        Err(e) => return println!("error: {}", e),
+    };
+    for (part, status) in parts {
+        let label = format!("Example {} part {}", example.name, part);
+        let (text, diff) = match status {
+            Status::Fail { expected, actual } => {
+                let diff = answers::diff(&expected, &actual);
+                (actual, Some(diff))
+            }
+            _ => ("ok".to_string(), None),
+        };
+        let change = history.update(&label, &text);
+        match diff {
+            Some(diff) => {
+                print_watched(&label, " FAILED", &change);
+                print!("{}", diff);
+            }
+            None => print_watched(&label, " ok", &change),
+        }
+    }
+}
+
+/// Solves the day and its examples every time its input or examples change,
+/// until interrupted.
+fn watch(options: RunOptions, interval: Duration) {
+    init_log(&options);
+    let (year, id) = options.days[0];
+    let day = advent::day(year, id).unwrap();
+    let files = options.input.path(year, day.number).into_iter().collect();
+    let dir = Path::new(examples::DIR).join(year.to_string()).join(day.id);
+    let mut watch = Watch::new(files, vec![dir]);
+    let mut history = History::default();
+    for run in 1.. {
+        while !watch.changed() {
+            std::thread::sleep(interval);
+        }
+        println!("\n== {} day {}, run {} ==", year, day.id, run);
+        watch_once(&options, day, &mut history);
+    }
+}
+
+/// Downloads the inputs of `days` of `year` that are not in `dir` yet. Exits
+/// with an error after the first one that fails.
+fn fetch(year: u32, days: &[u32], dir: &Path, base_url: Option<String>, session_file: &Path) {
+    let base_url = base_url
+        .or_else(|| std::env::var(fetch::BASE_URL_VAR).ok())
+        .unwrap_or_else(|| fetch::BASE_URL.to_string());
+    let mut session = None;
+    for &day in days {
+        let path = Source::Dir(dir.to_path_buf()).path(year, day).unwrap();
+        if path.exists() {
+            println!("Day {}: already in `{}`", day, path.display());
+            continue;
+        }
+        let token = session.get_or_insert_with(|| {
+            fetch::session(std::env::var(fetch::SESSION_VAR).ok(), session_file).unwrap_or_else(
+                |e| {
+                    eprintln!("error: {}", e);
+                    std::process::exit(1);
+                },
+            )
+        });
+        match fetch::fetch(&base_url, token, year, day, dir) {
+            Ok(Fetched::Downloaded(path)) | Ok(Fetched::Cached(path)) => {
+                println!("Day {}: saved to `{}`", day, path.display())
+            }
+            Err(e) => {
+                eprintln!("error: day {}: {}", day, e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Creates and registers the module of `id` of `year` in the crate in the
+/// working directory.
+fn new(year: u32, id: &str) {
+    match scaffold::create(Path::new("."), year, id) {
+        Ok(path) => println!(
+            "Created `{}`; add its examples under {}/{}/{}/",
+            path.display(),
+            examples::DIR,
+            year,
+            id
+        ),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
 
 fn main() {
-    let day = 25;
-    match day {
-        1 => day_1::one().unwrap(),
-        2 => day_2::two().unwrap(),
-        3 => day_3::three().unwrap(),
-        4 => day_4::four().unwrap(),
-        5 => day_5::five().unwrap(),
-        6 => day_6::six().unwrap(),
-        7 => day_7::seven().unwrap(),
-        8 => day_8::eight().unwrap(),
-        9 => day_9::nine().unwrap(),
-        10 => day_10::ten().unwrap(),
-        11 => day_11::eleven().unwrap(),
-        12 => day_12::twelve().unwrap(),
-        13 => day_13::thirteen().unwrap(),
-        14 => day_14::fourteen().unwrap(),
-        15 => day_15::fifteen().unwrap(),
-        16 => day_16::sixteen().unwrap(),
-        17 => day_17::seventeen().unwrap(),
-        18 => day_18::eighteen().unwrap(),
-        19 => day_19::nineteen().unwrap(),
-        20 => day_20::twenty().unwrap(),
-        21 => day_21::twentyone().unwrap(),
-        22 => day_22::twentytwo().unwrap(),
-        23 => day_23::twentythree().unwrap(),
-        24 => day_24b::twentyfour().unwrap(),
-        25 => day_25::twentyfive().unwrap(),
-        _ => unreachable!(),
+    let years = advent::YEARS
+        .iter()
+        .map(|year| (year.year, year.days.iter().map(|day| day.id).collect()))
+        .collect::<Vec<_>>();
+    let command = match cli::parse(std::env::args().skip(1), &years) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Help => println!("{}", cli::USAGE),
+        Command::Run(options) => run(options),
+        Command::Verify { options, answers } => verify(options, &answers),
+        Command::Record { options, answers } => record(options, &answers),
+        Command::Bench { options, bench } => self::bench(options, bench),
+        Command::Fetch {
+            year,
+            days,
+            dir,
+            base_url,
+            session_file,
+        } => fetch(year, &days, &dir, base_url, &session_file),
+        Command::Watch { options, interval } => watch(options, interval),
+        Command::New { year, id } => new(year, &id),
     }
 }
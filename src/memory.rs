@@ -0,0 +1,155 @@
+//! Allocation accounting for `--alloc-stats`. The binary installs
+//! [`Counting`] as its global allocator; it only counts once [`enable`] has
+//! been called, and costs one relaxed load per allocation until then.
+//!
+//! The counters are shared by all threads, so a day that uses rayon is
+//! measured in full, but so is anything else running at the same time, such
+//! as a day abandoned by `--timeout`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+// Signed, since memory allocated before counting started can be freed after
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// The system allocator, counting allocations while enabled.
+pub struct Counting;
+
+fn allocated(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+fn freed(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+/// Starts counting. Returns false, and leaves counting off, if [`Counting`]
+/// is not the global allocator.
+pub fn enable() -> bool {
+    ENABLED.store(true, Ordering::Relaxed);
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(std::hint::black_box(Box::new(0u8)));
+    let counted = ALLOCATIONS.load(Ordering::Relaxed) != before;
+    ENABLED.store(counted, Ordering::Relaxed);
+    counted
+}
+
+/// Allocations made while running one phase of a day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    /// Total bytes allocated; a reallocation counts its new size.
+    pub bytes: usize,
+    /// Most bytes live at once, above what was live when the phase started.
+    pub peak: usize,
+}
+
+/// Runs `f` and counts its allocations, or returns `None` for them if
+/// counting is off.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<Usage>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let res = f();
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: (PEAK.load(Ordering::Relaxed) - live).max(0) as usize,
+    };
+    (res, Some(usage))
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{}B", bytes)
+    } else {
+        format!("{:.1}{}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memory::{enable, format_bytes, measure, Counting};
+
+    #[global_allocator]
+    static ALLOC: Counting = Counting;
+
+    #[test]
+    fn counting() {
+        assert!(enable());
+        let (_, usage) = measure(|| {
+            let mut v = Vec::<u64>::with_capacity(100);
+            v.push(1);
+            drop(v);
+            let v = std::hint::black_box(vec![0u8; 50]);
+            drop(v);
+        });
+        let usage = usage.unwrap();
+        // Other tests allocate on their own threads at the same time, and
+        // measuring them resets the peak
+        assert!(usage.allocations >= 2);
+        assert!(usage.bytes >= 850);
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!("512B", format_bytes(512));
+        assert_eq!("1.5KiB", format_bytes(1536));
+        assert_eq!("3.0MiB", format_bytes(3 << 20));
+        assert_eq!("2048.0GiB", format_bytes(2 << 40));
+    }
+}
@@ -0,0 +1,323 @@
+//! Helpers for the input formats that keep coming back: numbers separated
+//! by commas, grids of digits, sections separated by blank lines and
+//! `x=a..b` ranges. Everything reports a `ParseError` pointing at the
+//! offending line and column.
+
+use crate::grid::Grid;
+use std::fmt;
+use std::str::FromStr;
+
+/// Why an input could not be parsed: what was expected, and where.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    /// The offending line, for the diagnostic.
+    pub text: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl ParseError {
+    /// Error for an input that ended before `expected`.
+    pub fn missing<S: AsRef<str>>(input: &[S], expected: &str) -> ParseError {
+        ParseError {
+            line: input.len() + 1,
+            column: 1,
+            expected: format!("{} before the end of the input", expected),
+            text: String::new(),
+        }
+    }
+
+    /// Renders the error the way rustc does, pointing at the column in the
+    /// line of the input read from `path`.
+    pub fn diagnostic(&self, path: &str) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        format!(
+            "error: expected {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}^\n",
+            self.expected,
+            gutter,
+            path,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            self.text,
+            gutter,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+/// One line of a day's input, remembering its position so that errors can
+/// point at it.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    /// 0-based index into the input.
+    pub index: usize,
+    pub text: &'a str,
+}
+
+/// The lines of `input`, numbered.
+pub fn lines<S: AsRef<str>>(input: &[S]) -> impl Iterator<Item = Line<'_>> {
+    input.iter().enumerate().map(|(index, s)| Line {
+        index,
+        text: s.as_ref(),
+    })
+}
+
+/// Splits the input into sections separated by blank lines, keeping the
+/// line numbers. Runs of blank lines do not produce empty sections.
+pub fn sections<S: AsRef<str>>(input: &[S]) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![];
+    let mut section = vec![];
+    for line in lines(input) {
+        if line.text.is_empty() {
+            if !section.is_empty() {
+                sections.push(std::mem::take(&mut section));
+            }
+        } else {
+            section.push(line);
+        }
+    }
+    if !section.is_empty() {
+        sections.push(section);
+    }
+    sections
+}
+
+/// Section `index` of `sections`, or an error pointing past the input.
+pub fn section<'a, 'b, S: AsRef<str>>(
+    input: &[S],
+    sections: &'b [Vec<Line<'a>>],
+    index: usize,
+    expected: &str,
+) -> Result<&'b [Line<'a>], ParseError> {
+    sections
+        .get(index)
+        .map(|s| s.as_slice())
+        .ok_or_else(|| ParseError::missing(input, expected))
+}
+
+/// All comma-separated numbers on all lines, e.g. `3,4,3,1,2`.
+pub fn comma_ints<T: FromStr, S: AsRef<str>>(input: &[S]) -> Result<Vec<T>, ParseError> {
+    let mut res = vec![];
+    for line in lines(input) {
+        res.extend(line.ints(',')?);
+    }
+    Ok(res)
+}
+
+/// A grid of decimal digits, one row per line.
+pub fn digit_grid<T: From<u8>, S: AsRef<str>>(input: &[S]) -> Result<Grid<T>, ParseError> {
+    Grid::parse_chars(lines(input), "a digit", |c| {
+        c.to_digit(10).map(|d| T::from(d as u8))
+    })
+}
+
+impl<'a> Line<'a> {
+    /// Line `index` of `input`, or an error if the input is shorter.
+    pub fn at<S: AsRef<str>>(
+        input: &'a [S],
+        index: usize,
+        expected: &str,
+    ) -> Result<Line<'a>, ParseError> {
+        input
+            .get(index)
+            .map(|s| Line {
+                index,
+                text: s.as_ref(),
+            })
+            .ok_or_else(|| ParseError::missing(input, expected))
+    }
+
+    /// Error at byte offset `offset` of the line.
+    pub fn error_at_offset(&self, offset: usize, expected: &str) -> ParseError {
+        let offset = offset.min(self.text.len());
+        ParseError {
+            line: self.index + 1,
+            column: self.text[..offset].chars().count() + 1,
+            expected: expected.to_string(),
+            text: self.text.to_string(),
+        }
+    }
+
+    /// Error at the start of `part`, which should be a slice of the line as
+    /// returned by `split` and friends. Anything else points at the start of
+    /// the line.
+    pub fn error(&self, part: &str, expected: &str) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(0);
+        self.error_at_offset(offset, expected)
+    }
+
+    /// Error just past the end of the line, for missing fields.
+    pub fn error_at_end(&self, expected: &str) -> ParseError {
+        self.error_at_offset(self.text.len(), expected)
+    }
+
+    /// Unwraps a field taken from the line, or reports it as missing.
+    pub fn field(&self, field: Option<&'a str>, expected: &str) -> Result<&'a str, ParseError> {
+        field.ok_or_else(|| self.error_at_end(expected))
+    }
+
+    /// Parses `part` of the line as a number.
+    pub fn num<T: FromStr>(&self, part: &str) -> Result<T, ParseError> {
+        part.parse().map_err(|_| self.error(part, "a number"))
+    }
+
+    /// The numbers of the line separated by `sep`.
+    pub fn ints<T: FromStr>(&self, sep: char) -> Result<Vec<T>, ParseError> {
+        self.text.split(sep).map(|s| self.num(s)).collect()
+    }
+
+    /// Parses a range like `x=-10..12` from `part` of the line, checking that
+    /// it is for `name`. The bounds are returned in the order they appear.
+    pub fn range<T: FromStr>(&self, part: &'a str, name: &str) -> Result<(T, T), ParseError> {
+        let expected = format!("{}=a..b", name);
+        let part = part.trim();
+        let bounds = part
+            .strip_prefix(name)
+            .and_then(|s| s.strip_prefix('='))
+            .ok_or_else(|| self.error(part, &expected))?;
+        let mut bounds = bounds.split("..");
+        let start = self.num(self.field(bounds.next(), &expected)?)?;
+        let end = self.num(self.field(bounds.next(), &expected)?)?;
+        match bounds.next() {
+            Some(extra) => Err(self.error(extra, "the end of the range")),
+            None => Ok((start, end)),
+        }
+    }
+
+    /// The digits of the line in base `radix`.
+    pub fn digits(&self, radix: u32) -> Result<Vec<u32>, ParseError> {
+        self.text
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(radix)
+                    .ok_or_else(|| self.error_at_offset(i, "a digit"))
+            })
+            .collect()
+    }
+
+    /// Maps every character of the line with `f`, which returns `None` for
+    /// characters that are not `expected`.
+    pub fn chars<T, F: Fn(char) -> Option<T>>(
+        &self,
+        expected: &str,
+        f: F,
+    ) -> Result<Vec<T>, ParseError> {
+        self.text
+            .char_indices()
+            .map(|(i, c)| f(c).ok_or_else(|| self.error_at_offset(i, expected)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+    use crate::parsing::{comma_ints, digit_grid, lines, section, sections, Line, ParseError};
+
+    #[test]
+    fn errors() {
+        let input = ["forward 5", "down x"];
+        let line = lines(&input).nth(1).unwrap();
+        let mut fields = line.text.split(' ');
+        assert_eq!(Ok("down"), line.field(fields.next(), "a direction"));
+        let err = line.num::<i32>(fields.next().unwrap()).unwrap_err();
+        assert_eq!(
+            ParseError {
+                line: 2,
+                column: 6,
+                expected: "a number".to_string(),
+                text: "down x".to_string(),
+            },
+            err
+        );
+        assert_eq!(
+            "error: expected a number\n --> 2_input:2:6\n  |\n2 | down x\n  |      ^\n",
+            err.diagnostic("2_input")
+        );
+        assert_eq!(
+            "line 2, column 7: expected a count",
+            line.field(fields.next(), "a count")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(3, Line::at(&input, 2, "a header").unwrap_err().line);
+    }
+
+    #[test]
+    fn chars() {
+        let line = lines(&["12a4"]).next().unwrap();
+        assert_eq!(3, line.digits(10).unwrap_err().column);
+        assert_eq!(Ok(vec![1, 2, 10, 4]), line.digits(16));
+        let cell = |c| match c {
+            '#' => Some(true),
+            'ä' => Some(false),
+            _ => None,
+        };
+        let line = lines(&["#ä.#"]).next().unwrap();
+        assert_eq!(3, line.chars("# or ä", cell).unwrap_err().column);
+        let line = lines(&["#ä"]).next().unwrap();
+        assert_eq!(Ok(vec![true, false]), line.chars("# or ä", cell));
+    }
+
+    #[test]
+    fn toolkit() {
+        assert_eq!(
+            Ok(vec![3, 4, 3, 1, 2]),
+            comma_ints::<i32, _>(&["3,4", "3,1,2"])
+        );
+        assert_eq!((2, 3), {
+            let err = comma_ints::<i32, _>(&["3,4", "3,,2"]).unwrap_err();
+            (err.line, err.column)
+        });
+
+        let grid: Grid<usize> = digit_grid(&["12", "34"]).unwrap();
+        assert_eq!(Grid::new(vec![vec![1, 2], vec![3, 4]]), grid);
+        assert_eq!((2, 3), {
+            let err = digit_grid::<usize, _>(&["12", "345"]).unwrap_err();
+            (err.line, err.column)
+        });
+        assert_eq!((3, 2), {
+            let err = digit_grid::<usize, _>(&["12", "34", "5"]).unwrap_err();
+            (err.line, err.column)
+        });
+
+        let input = ["a", "b", "", "", "c"];
+        let sections = sections(&input);
+        assert_eq!(2, sections.len());
+        assert_eq!(4, sections[1][0].index);
+        assert_eq!("b", section(&input, &sections, 0, "a").unwrap()[1].text);
+        assert_eq!(6, section(&input, &sections, 2, "c").unwrap_err().line);
+
+        let line = lines(&["target area: x=20..30, y=-10..-5"]).next().unwrap();
+        let mut parts = line.text[13..].split(',');
+        assert_eq!(Ok((20, 30)), line.range::<i32>(parts.next().unwrap(), "x"));
+        let err = line.range::<i32>(parts.next().unwrap(), "z").unwrap_err();
+        assert_eq!((24, "z=a..b"), (err.column, err.expected.as_str()));
+        let line = lines(&["x=1..2..3"]).next().unwrap();
+        let err = line.range::<i32>(line.text, "x").unwrap_err();
+        assert_eq!(
+            (9, "the end of the range"),
+            (err.column, err.expected.as_str())
+        );
+    }
+}
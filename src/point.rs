@@ -0,0 +1,279 @@
+//! Integer points and vectors in two and three dimensions. Both support the
+//! usual arithmetic, distances and neighbors, and convert to and from
+//! tuples; `Point2` also converts to and from `Grid` coordinates.
+
+use std::convert::TryFrom;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+const fn p2(x: i32, y: i32) -> Point2 {
+    Point2 { x, y }
+}
+
+impl Point2 {
+    pub const ZERO: Point2 = p2(0, 0);
+
+    /// The four orthogonal unit steps, in reading order.
+    pub const ORTHOGONAL: [Point2; 4] = [p2(0, -1), p2(-1, 0), p2(1, 0), p2(0, 1)];
+
+    /// The eight steps to the surrounding cells, in reading order.
+    pub const ADJACENT: [Point2; 8] = [
+        p2(-1, -1),
+        p2(0, -1),
+        p2(1, -1),
+        p2(-1, 0),
+        p2(1, 0),
+        p2(-1, 1),
+        p2(0, 1),
+        p2(1, 1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Point2 {
+        p2(x, y)
+    }
+
+    pub fn abs(self) -> Point2 {
+        p2(self.x.abs(), self.y.abs())
+    }
+
+    pub fn signum(self) -> Point2 {
+        p2(self.x.signum(), self.y.signum())
+    }
+
+    pub fn manhattan(self, other: Point2) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Point2) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Wraps each coordinate into `0..size`, as on a torus.
+    pub fn wrap(self, size: Point2) -> Point2 {
+        p2(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+
+    /// The orthogonal neighbors.
+    pub fn nbors(self) -> impl Iterator<Item = Point2> {
+        Point2::ORTHOGONAL.iter().map(move |&d| self + d)
+    }
+
+    /// The orthogonal and diagonal neighbors.
+    pub fn diag_nbors(self) -> impl Iterator<Item = Point2> {
+        Point2::ADJACENT.iter().map(move |&d| self + d)
+    }
+}
+
+impl Point3 {
+    pub const ZERO: Point3 = Point3::new(0, 0, 0);
+
+    /// The six unit steps along an axis.
+    pub const ORTHOGONAL: [Point3; 6] = [
+        Point3::new(0, 0, -1),
+        Point3::new(0, -1, 0),
+        Point3::new(-1, 0, 0),
+        Point3::new(1, 0, 0),
+        Point3::new(0, 1, 0),
+        Point3::new(0, 0, 1),
+    ];
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn abs(self) -> Point3 {
+        Point3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn signum(self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn manhattan(self, other: Point3) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(self, other: Point3) -> u32 {
+        let x = self.x.abs_diff(other.x);
+        x.max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    /// The six neighbors sharing a face.
+    pub fn nbors(self) -> impl Iterator<Item = Point3> {
+        Point3::ORTHOGONAL.iter().map(move |&d| self + d)
+    }
+
+    /// The 26 neighbors sharing a face, an edge or a corner.
+    pub fn diag_nbors(self) -> impl Iterator<Item = Point3> {
+        (-1..=1)
+            .flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| Point3::new(x, y, z))))
+            .filter(|&d| d != Point3::ZERO)
+            .map(move |d| self + d)
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<i32> for $point {
+            type Output = $point;
+
+            fn mul(self, scale: i32) -> $point {
+                $point { $($field: self.$field * scale),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl From<(i32, i32)> for Point2 {
+    fn from((x, y): (i32, i32)) -> Point2 {
+        p2(x, y)
+    }
+}
+
+impl From<Point2> for (i32, i32) {
+    fn from(p: Point2) -> (i32, i32) {
+        (p.x, p.y)
+    }
+}
+
+/// Grid coordinates, which are assumed to fit in an `i32`.
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Point2 {
+        p2(x as i32, y as i32)
+    }
+}
+
+/// Grid coordinates, or an error for a point left of or above the origin.
+impl TryFrom<Point2> for (usize, usize) {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(p: Point2) -> Result<(usize, usize), Self::Error> {
+        Ok((usize::try_from(p.x)?, usize::try_from(p.y)?))
+    }
+}
+
+impl From<(i32, i32, i32)> for Point3 {
+    fn from((x, y, z): (i32, i32, i32)) -> Point3 {
+        Point3::new(x, y, z)
+    }
+}
+
+impl From<Point3> for (i32, i32, i32) {
+    fn from(p: Point3) -> (i32, i32, i32) {
+        (p.x, p.y, p.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::{Point2, Point3};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(3, 4);
+        assert_eq!(Point2::new(4, 2), a + b);
+        assert_eq!(Point2::new(-2, -6), a - b);
+        assert_eq!(Point2::new(-1, 2), -a);
+        assert_eq!(Point2::new(3, -6), a * 3);
+        let mut c = a;
+        c += b;
+        c -= a * 2;
+        assert_eq!(Point2::new(2, 6), c);
+        assert_eq!(
+            Point3::new(2, 0, -3),
+            Point3::new(1, 1, -1) * 2 - Point3::new(0, 2, 1)
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point2::new(1, -2);
+        assert_eq!(7, a.manhattan(Point2::new(-3, 1)));
+        assert_eq!(4, a.chebyshev(Point2::new(-3, 1)));
+        let a = Point3::new(1105, -1205, 1229);
+        let b = Point3::new(-92, -2380, -20);
+        assert_eq!(3621, a.manhattan(b));
+        assert_eq!(1249, a.chebyshev(b));
+        assert_eq!(
+            Point2::new(4, 0),
+            Point2::new(-1, 5).wrap(Point2::new(5, 5))
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        let p = Point2::new(0, 0);
+        assert_eq!(4, p.nbors().count());
+        assert_eq!(8, p.diag_nbors().count());
+        assert!(p.diag_nbors().all(|n| n.chebyshev(p) == 1));
+        assert!(p.nbors().all(|n| n.manhattan(p) == 1));
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(6, p.nbors().count());
+        assert!(p.nbors().all(|n| n.manhattan(p) == 1));
+        assert_eq!(26, p.diag_nbors().count());
+        assert!(p.diag_nbors().all(|n| n.chebyshev(p) == 1));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Point2::new(1, 2), (1, 2).into());
+        assert_eq!(Point2::new(1, 2), (1usize, 2usize).into());
+        assert_eq!(Ok((1, 2)), <(usize, usize)>::try_from(Point2::new(1, 2)));
+        assert!(<(usize, usize)>::try_from(Point2::new(1, -2)).is_err());
+        assert_eq!((1, 2, 3), Point3::new(1, 2, 3).into());
+    }
+}
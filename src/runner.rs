@@ -0,0 +1,683 @@
+use crate::answer::Answer;
+use crate::cancel::{self, Cancelled, Token};
+use crate::input::{self, InputError, Source};
+use crate::memory::{self, format_bytes, Usage};
+use crate::parsing::ParseError;
+use crate::solution::Day;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Which parts of a day to solve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parts {
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    pub fn one(self) -> bool {
+        self != Parts::Two
+    }
+
+    pub fn two(self) -> bool {
+        self != Parts::One
+    }
+}
+
+/// How solving one part ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Ok(Answer),
+    Panic {
+        message: String,
+        /// `file:line:column` of the panic, if it happened on the thread
+        /// running the day.
+        location: Option<String>,
+    },
+    Unimplemented,
+    /// The parser rejected the input.
+    Invalid(ParseError),
+    /// The day ran past its `--timeout`.
+    Timeout,
+}
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Ok(_) => "ok",
+            Outcome::Panic { .. } => "panic",
+            Outcome::Unimplemented => "unimplemented",
+            Outcome::Invalid(_) => "parse_error",
+            Outcome::Timeout => "timeout",
+        }
+    }
+
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            Outcome::Ok(answer) => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Ok(answer) => write!(f, "{}", answer),
+            Outcome::Panic {
+                message,
+                location: Some(location),
+            } => write!(f, "panicked at {}: {}", location, message),
+            Outcome::Panic { message, .. } => write!(f, "panicked: {}", message),
+            Outcome::Unimplemented => write!(f, "not implemented"),
+            // The error itself is shown once, as a diagnostic with the input
+            Outcome::Invalid(_) => write!(f, "invalid input"),
+            Outcome::Timeout => write!(f, "timed out"),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub outcome: Outcome,
+    pub time: Duration,
+    /// Allocations made by the part, if `--alloc-stats` is counting them.
+    pub alloc: Option<Usage>,
+}
+
+pub struct DayResult {
+    pub day: &'static Day,
+    pub parse_time: Duration,
+    pub parse_alloc: Option<Usage>,
+    pub part1: Option<PartResult>,
+    pub part2: Option<PartResult>,
+}
+
+impl DayResult {
+    pub fn total(&self) -> Duration {
+        self.parse_time
+            + self.part1.as_ref().map(|p| p.time).unwrap_or_default()
+            + self.part2.as_ref().map(|p| p.time).unwrap_or_default()
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = (u8, &PartResult)> {
+        let part1 = self.part1.as_ref().map(|p| (1, p));
+        let part2 = self.part2.as_ref().map(|p| (2, p));
+        part1.into_iter().chain(part2)
+    }
+
+    fn apply(&mut self, phase: Phase) {
+        match phase {
+            Phase::Parsed(time, alloc) => {
+                self.parse_time = time;
+                self.parse_alloc = alloc;
+            }
+            Phase::Part(1, part) => self.part1 = Some(part),
+            Phase::Part(_, part) => self.part2 = Some(part),
+        }
+    }
+}
+
+fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Wraps the panic hook so that panics inside `catch` only record their
+/// location instead of printing to stderr. Panics elsewhere are reported as
+/// usual.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(|c| c.get()) {
+                let location = info
+                    .location()
+                    .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+                LOCATION.with(|l| *l.borrow_mut() = location);
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Runs `f`, turning a panic into an `Outcome` instead of unwinding through
+/// the rest of the run. `unimplemented!()` and `todo!()` are told apart by
+/// their message.
+pub(crate) fn catch<T, F: FnOnce() -> T>(f: F) -> Result<T, Outcome> {
+    install_hook();
+    CATCHING.with(|c| c.set(true));
+    LOCATION.with(|l| l.borrow_mut().take());
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|c| c.set(false));
+    res.map_err(|payload| {
+        if payload.is::<Cancelled>() {
+            return Outcome::Timeout;
+        }
+        let message = panic_message(&*payload);
+        if message.starts_with("not implemented") || message.starts_with("not yet implemented") {
+            Outcome::Unimplemented
+        } else {
+            Outcome::Panic {
+                message,
+                location: LOCATION.with(|l| l.borrow_mut().take()),
+            }
+        }
+    })
+}
+
+fn solve(f: &dyn Fn() -> Answer) -> Outcome {
+    match catch(f) {
+        Ok(answer) => Outcome::Ok(answer),
+        Err(outcome) => outcome,
+    }
+}
+
+/// A finished phase of a day.
+enum Phase {
+    Parsed(Duration, Option<Usage>),
+    Part(u8, PartResult),
+}
+
+/// Parses `lines` and solves the selected parts, reporting each phase as it
+/// finishes. Panics in the parser or a part are caught; if the parser
+/// panics or rejects the input, every selected part ends with that outcome.
+fn solve_day(day: &Day, parts: Parts, lines: &[String], report: &mut dyn FnMut(Phase)) {
+    let ((parsed, parse_time), parse_alloc) = memory::measure(|| {
+        timed(|| catch(|| day.solver.parse(lines)).and_then(|r| r.map_err(Outcome::Invalid)))
+    });
+    report(Phase::Parsed(parse_time, parse_alloc));
+    let mut part = |n: u8, f: &dyn Fn(&dyn Any) -> Answer| {
+        let ((outcome, time), alloc) = match &parsed {
+            Ok(parsed) => memory::measure(|| timed(|| solve(&|| f(&**parsed)))),
+            Err(outcome) => ((outcome.clone(), Duration::default()), None),
+        };
+        report(Phase::Part(
+            n,
+            PartResult {
+                outcome,
+                time,
+                alloc,
+            },
+        ));
+    };
+
+    if parts.one() {
+        part(1, &|parsed| day.solver.part1(parsed));
+    }
+    if parts.two() {
+        part(2, &|parsed| day.solver.part2(parsed));
+    }
+}
+
+/// Solves `day` on a worker thread, giving up after `limit`. Parts that did
+/// not finish in time end with `Outcome::Timeout`, and the worker's token is
+/// cancelled so that searches calling `cancel::check` stop; a day that never
+/// checks keeps running in the background until the process exits.
+fn solve_with_timeout(
+    day: &'static Day,
+    parts: Parts,
+    lines: Vec<String>,
+    limit: Duration,
+    result: &mut DayResult,
+) {
+    let token = Token::new();
+    let (sender, receiver) = mpsc::channel();
+    let worker = token.clone();
+    let start = Instant::now();
+    thread::spawn(move || {
+        cancel::with_token(&worker, || {
+            solve_day(day, parts, &lines, &mut |phase| {
+                // The receiver is gone once the day has timed out
+                let _ = sender.send(phase);
+            })
+        })
+    });
+
+    let mut parsed = false;
+    loop {
+        let left = limit.checked_sub(start.elapsed()).unwrap_or_default();
+        match receiver.recv_timeout(left) {
+            Ok(phase) => {
+                parsed |= matches!(phase, Phase::Parsed(..));
+                result.apply(phase);
+            }
+            Err(RecvTimeoutError::Disconnected) => return,
+            Err(RecvTimeoutError::Timeout) => break,
+        }
+    }
+
+    token.cancel();
+    let elapsed = start.elapsed();
+    if !parsed {
+        result.parse_time = elapsed;
+    }
+    for n in [1, 2].iter().cloned() {
+        let selected = if n == 1 { parts.one() } else { parts.two() };
+        let done = if n == 1 { &result.part1 } else { &result.part2 };
+        if selected && done.is_none() {
+            let time = elapsed.checked_sub(result.total()).unwrap_or_default();
+            result.apply(Phase::Part(
+                n,
+                PartResult {
+                    outcome: Outcome::Timeout,
+                    time,
+                    alloc: None,
+                },
+            ));
+        }
+    }
+}
+
+/// Loads the input of `day` and solves the selected parts, timing each phase.
+/// With a `timeout` the day runs on a worker thread and is abandoned once it
+/// overruns.
+pub fn run(
+    day: &'static Day,
+    parts: Parts,
+    input: &Source,
+    timeout: Option<Duration>,
+) -> Result<DayResult, InputError> {
+    let lines = input::load(input, day.year, day.number)?;
+    let mut result = DayResult {
+        day,
+        parse_time: Duration::default(),
+        parse_alloc: None,
+        part1: None,
+        part2: None,
+    };
+    match timeout {
+        Some(limit) => solve_with_timeout(day, parts, lines, limit, &mut result),
+        None => solve_day(day, parts, &lines, &mut |phase| result.apply(phase)),
+    }
+    Ok(result)
+}
+
+/// Lists every part that did not produce an answer, or `None` if all did.
+pub fn failure_report(results: &[DayResult]) -> Option<String> {
+    let failures = results
+        .iter()
+        .flat_map(|r| {
+            r.parts()
+                .filter(|(_, p)| p.outcome.answer().is_none())
+                .map(move |(part, p)| format!("  day {} part {}: {}\n", r.day.id, part, p.outcome))
+        })
+        .collect::<Vec<_>>();
+    if failures.is_empty() {
+        None
+    } else {
+        Some(format!(
+            "{} part(s) failed:\n{}",
+            failures.len(),
+            failures.concat()
+        ))
+    }
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1000.0)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+fn format_part(part: &Option<PartResult>) -> String {
+    part.as_ref()
+        .map(|p| format_duration(p.time))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Renders the per-phase timings of `results` as a table, with each day's
+/// share of the total so that the slow days stand out.
+pub fn timing_table(results: &[DayResult]) -> String {
+    let total = results.iter().map(|r| r.total()).sum::<Duration>();
+    let mut res = format!(
+        "{:>4} {:>10} {:>10} {:>10} {:>10} {:>6}\n",
+        "Day", "Parse", "Part 1", "Part 2", "Total", "Share"
+    );
+    for r in results {
+        let share = if total.as_nanos() == 0 {
+            0.0
+        } else {
+            100.0 * r.total().as_secs_f64() / total.as_secs_f64()
+        };
+        res += &format!(
+            "{:>4} {:>10} {:>10} {:>10} {:>10} {:>5.1}%\n",
+            r.day.id,
+            format_duration(r.parse_time),
+            format_part(&r.part1),
+            format_part(&r.part2),
+            format_duration(r.total()),
+            share
+        );
+    }
+    res += &format!(
+        "{:>4} {:>10} {:>10} {:>10} {:>10}\n",
+        "All",
+        format_duration(results.iter().map(|r| r.parse_time).sum()),
+        format_duration(
+            results
+                .iter()
+                .filter_map(|r| r.part1.as_ref())
+                .map(|p| p.time)
+                .sum()
+        ),
+        format_duration(
+            results
+                .iter()
+                .filter_map(|r| r.part2.as_ref())
+                .map(|p| p.time)
+                .sum()
+        ),
+        format_duration(total),
+    );
+    res
+}
+
+fn format_alloc(alloc: Option<Usage>) -> [String; 3] {
+    match alloc {
+        Some(usage) => [
+            usage.allocations.to_string(),
+            format_bytes(usage.bytes),
+            format_bytes(usage.peak),
+        ],
+        None => ["-".to_string(), "-".to_string(), "-".to_string()],
+    }
+}
+
+/// Renders the allocations of every phase of `results` as a table: how many
+/// allocations it made, how many bytes they totalled and the most memory it
+/// had live at once.
+pub fn alloc_table(results: &[DayResult]) -> String {
+    let mut res = format!(
+        "{:>4} {:>6} {:>12} {:>10} {:>10}\n",
+        "Day", "Phase", "Allocations", "Bytes", "Peak"
+    );
+    for r in results {
+        let parts = r.parts().map(|(n, p)| (format!("part {}", n), p.alloc));
+        let phases = std::iter::once(("parse".to_string(), r.parse_alloc)).chain(parts);
+        for (phase, alloc) in phases {
+            let [allocations, bytes, peak] = format_alloc(alloc);
+            res += &format!(
+                "{:>4} {:>6} {:>12} {:>10} {:>10}\n",
+                r.day.id, phase, allocations, bytes, peak
+            );
+        }
+    }
+    res
+}
+
+fn json_string(s: &str) -> String {
+    let mut res = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            '\n' => res += "\\n",
+            '\r' => res += "\\r",
+            '\t' => res += "\\t",
+            c if (c as u32) < 0x20 => res += &format!("\\u{:04x}", c as u32),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(n) => n.to_string(),
+        Answer::Str(s) => json_string(s),
+        Answer::Grid(rows) => format!(
+            "[{}]",
+            rows.iter()
+                .map(|row| json_string(row))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn json_part(day: &Day, part: u8, result: &PartResult) -> String {
+    let mut res = format!(
+        "{{\"year\": {}, \"day\": {}, \"part\": {}, \"status\": \"{}\", \"duration_us\": {}",
+        day.year,
+        json_string(day.id),
+        part,
+        result.outcome.status(),
+        result.time.as_micros()
+    );
+    if let Some(usage) = result.alloc {
+        res += &format!(
+            ", \"allocations\": {}, \"alloc_bytes\": {}, \"peak_bytes\": {}",
+            usage.allocations, usage.bytes, usage.peak
+        );
+    }
+    match &result.outcome {
+        Outcome::Ok(answer) => res += &format!(", \"answer\": {}", json_answer(answer)),
+        Outcome::Panic { message, location } => {
+            res += &format!(", \"message\": {}", json_string(message));
+            if let Some(location) = location {
+                res += &format!(", \"location\": {}", json_string(location));
+            }
+        }
+        Outcome::Invalid(error) => {
+            res += &format!(
+                ", \"message\": {}, \"line\": {}, \"column\": {}",
+                json_string(&format!("expected {}", error.expected)),
+                error.line,
+                error.column
+            );
+        }
+        Outcome::Unimplemented | Outcome::Timeout => {}
+    }
+    res + "}"
+}
+
+/// Renders `results` as a JSON array with one object per solved part.
+/// Grid answers are arrays of rows.
+pub fn to_json(results: &[DayResult]) -> String {
+    let parts = results
+        .iter()
+        .flat_map(|r| r.parts().map(move |(part, p)| json_part(r.day, part, p)))
+        .map(|part| format!("  {}", part))
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", parts.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::cancel;
+    use crate::memory::Usage;
+    use crate::parsing::ParseError;
+    use crate::runner::{
+        alloc_table, catch, format_duration, json_part, solve, solve_with_timeout, to_json,
+        DayResult, Outcome, PartResult, Parts,
+    };
+    use crate::solution::{Day, Solution};
+    use std::time::Duration;
+
+    struct Spin;
+
+    impl Solution for Spin {
+        type Parsed = ();
+
+        fn parse<S: AsRef<str>>(_input: &[S]) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Answer {
+            1.into()
+        }
+
+        fn part2(_input: &()) -> Answer {
+            loop {
+                cancel::check();
+            }
+        }
+    }
+
+    static SPIN: Day = Day {
+        year: 2021,
+        id: "1",
+        number: 1,
+        solver: &Spin,
+    };
+
+    #[test]
+    fn durations() {
+        assert_eq!("12µs", format_duration(Duration::from_micros(12)));
+        assert_eq!("1.50ms", format_duration(Duration::from_micros(1500)));
+        assert_eq!("2.25s", format_duration(Duration::from_millis(2250)));
+    }
+
+    #[test]
+    fn outcomes() {
+        assert_eq!(Outcome::Ok(Answer::Int(1)), solve(&|| 1.into()));
+        assert_eq!(Outcome::Unimplemented, solve(&|| unimplemented!()));
+        match solve(&|| panic!("bad {}", 7)) {
+            Outcome::Panic {
+                message,
+                location: Some(location),
+            } => {
+                assert_eq!("bad 7", message);
+                assert!(location.starts_with("src/runner.rs:"));
+            }
+            outcome => panic!("{:?}", outcome),
+        }
+        assert_eq!(Err::<(), _>(Outcome::Unimplemented), catch(|| todo!()));
+        match catch(|| "x".parse::<i32>().unwrap()) {
+            Err(Outcome::Panic { message, .. }) => assert!(message.contains("ParseIntError")),
+            outcome => panic!("{:?}", outcome),
+        }
+        let invalid = Outcome::Invalid(ParseError::missing(&["1"], "a number"));
+        assert_eq!("invalid input", invalid.to_string());
+    }
+
+    #[test]
+    fn timeout() {
+        let mut result = DayResult {
+            day: &SPIN,
+            parse_time: Duration::default(),
+            parse_alloc: None,
+            part1: None,
+            part2: None,
+        };
+        let limit = Duration::from_millis(50);
+        solve_with_timeout(&SPIN, Parts::Both, vec![], limit, &mut result);
+        assert_eq!(Outcome::Ok(Answer::Int(1)), result.part1.unwrap().outcome);
+        assert_eq!(Outcome::Timeout, result.part2.unwrap().outcome);
+    }
+
+    #[test]
+    fn json() {
+        let day = Day {
+            year: 2021,
+            id: "24b",
+            number: 24,
+            solver: &crate::y2021::day_1::One,
+        };
+        let part = |outcome| PartResult {
+            outcome,
+            time: Duration::from_micros(15),
+            alloc: None,
+        };
+        assert_eq!(
+            r##"{"year": 2021, "day": "24b", "part": 1, "status": "ok", "duration_us": 15, "answer": ["#.", "\"\\"]}"##,
+            json_part(
+                &day,
+                1,
+                &part(Outcome::Ok(Answer::Grid(vec![
+                    "#.".to_string(),
+                    "\"\\".to_string()
+                ])))
+            )
+        );
+        assert_eq!(
+            r#"{"year": 2021, "day": "24b", "part": 2, "status": "panic", "duration_us": 15, "message": "a\nb"}"#,
+            json_part(
+                &day,
+                2,
+                &part(Outcome::Panic {
+                    message: "a\nb".to_string(),
+                    location: None
+                })
+            )
+        );
+        assert_eq!(
+            r#"{"year": 2021, "day": "24b", "part": 2, "status": "unimplemented", "duration_us": 15}"#,
+            json_part(&day, 2, &part(Outcome::Unimplemented))
+        );
+        assert_eq!("[]\n", to_json(&[]));
+    }
+
+    #[test]
+    fn allocations() {
+        let usage = Usage {
+            allocations: 3,
+            bytes: 2048,
+            peak: 1024,
+        };
+        let result = DayResult {
+            day: &SPIN,
+            parse_time: Duration::default(),
+            parse_alloc: None,
+            part1: Some(PartResult {
+                outcome: Outcome::Ok(Answer::Int(1)),
+                time: Duration::from_micros(15),
+                alloc: Some(usage),
+            }),
+            part2: None,
+        };
+        assert_eq!(
+            concat!(
+                " Day  Phase  Allocations      Bytes       Peak\n",
+                "   1  parse            -          -          -\n",
+                "   1 part 1            3     2.0KiB     1.0KiB\n",
+            ),
+            alloc_table(&[result])
+        );
+        let part = PartResult {
+            outcome: Outcome::Unimplemented,
+            time: Duration::from_micros(15),
+            alloc: Some(usage),
+        };
+        assert_eq!(
+            r#"{"year": 2021, "day": "1", "part": 1, "status": "unimplemented", "duration_us": 15, "allocations": 3, "alloc_bytes": 2048, "peak_bytes": 1024}"#,
+            json_part(&SPIN, 1, &part)
+        );
+    }
+}
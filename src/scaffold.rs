@@ -0,0 +1,543 @@
+//! Generating a new day module from `templates/day.rs` and registering it in
+//! its year's module, `src/y<year>/mod.rs`. The template uses `{{year}}`,
+//! `{{id}}` for the solver id, e.g. `15b`, `{{Name}}` for the day number in
+//! words, e.g. `Fifteen`, and `{{name}}` for the same in lowercase.
+//!
+//! The first day of a new year also creates the year's module and registers
+//! it in `src/lib.rs`. Registries are rewritten as rustfmt lays them out.
+
+use regex::Regex;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+pub const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// The registry of years, relative to the crate root.
+pub const LIB: &str = "src/lib.rs";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The year is not one with puzzles.
+    InvalidYear(u32),
+    /// The id is not a day from 1 to 25 with an optional letter suffix.
+    InvalidDay(String),
+    /// The module file already exists.
+    Exists(PathBuf),
+    /// The registry already has the module or a solver with the id.
+    Registered(String),
+    /// The file has no module list or registry that can be added to.
+    NoRegistry(PathBuf),
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::InvalidYear(year) => {
+                write!(f, "invalid year {}, expected 2015 or later", year)
+            }
+            ScaffoldError::InvalidDay(id) => write!(
+                f,
+                "invalid day `{}`, expected 1 to 25 with an optional letter, e.g. 15b",
+                id
+            ),
+            ScaffoldError::Exists(path) => {
+                write!(f, "`{}` already exists, not overwriting it", path.display())
+            }
+            ScaffoldError::Registered(what) => write!(f, "{} is already registered", what),
+            ScaffoldError::NoRegistry(path) => {
+                write!(f, "cannot find the registry in `{}`", path.display())
+            }
+            ScaffoldError::Io { path, error } => {
+                write!(f, "cannot access `{}`: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+const ONES: [&str; 20] = [
+    "",
+    "One",
+    "Two",
+    "Three",
+    "Four",
+    "Five",
+    "Six",
+    "Seven",
+    "Eight",
+    "Nine",
+    "Ten",
+    "Eleven",
+    "Twelve",
+    "Thirteen",
+    "Fourteen",
+    "Fifteen",
+    "Sixteen",
+    "Seventeen",
+    "Eighteen",
+    "Nineteen",
+];
+
+/// The day number in words, as used for solver types: `TwentyOne`.
+pub fn number_name(number: u32) -> String {
+    match number {
+        n if n < 20 => ONES[n as usize].to_string(),
+        n => format!("Twenty{}", ONES[n as usize - 20]),
+    }
+}
+
+/// Splits an id like `15b` into its day number, or fails if it is not a day
+/// of the calendar.
+pub fn day_number(id: &str) -> Result<u32, ScaffoldError> {
+    let invalid = || ScaffoldError::InvalidDay(id.to_string());
+    let digits = id.trim_end_matches(|c: char| c.is_ascii_lowercase());
+    if id.len() - digits.len() > 1 || digits.starts_with('0') {
+        return Err(invalid());
+    }
+    match digits.parse() {
+        Ok(n) if (1..=25).contains(&n) => Ok(n),
+        _ => Err(invalid()),
+    }
+}
+
+fn check_year(year: u32) -> Result<u32, ScaffoldError> {
+    match year {
+        2015..=9999 => Ok(year),
+        _ => Err(ScaffoldError::InvalidYear(year)),
+    }
+}
+
+/// The source of a new module for `id` in `year`.
+pub fn render(year: u32, id: &str) -> Result<String, ScaffoldError> {
+    let name = number_name(day_number(id)?);
+    Ok(TEMPLATE
+        .replace("{{year}}", &check_year(year)?.to_string())
+        .replace("{{id}}", id)
+        .replace("{{Name}}", &name)
+        .replace("{{name}}", &name.to_lowercase()))
+}
+
+/// The module of a year without days.
+pub fn year_module(year: u32) -> String {
+    format!(
+        "//! Solutions for {}.\n\n\
+         use crate::solution::Day;\n\n\
+         /// Every solver, in run order. The first entry for a day is the one that\n\
+         /// ranges and `all` run.\n\
+         pub const DAYS: &[Day] = &[];\n",
+        year
+    )
+}
+
+fn no_registry(path: &Path) -> ScaffoldError {
+    ScaffoldError::NoRegistry(path.to_path_buf())
+}
+
+/// Adds `pub mod <module>;` next to the other `day_` or `y` modules in
+/// `source`, keeping them sorted. The first day module goes after the doc
+/// comment. `source` was read from `path`.
+pub fn add_module(path: &Path, source: &str, module: &str) -> Result<String, ScaffoldError> {
+    let prefix = if module.starts_with("day_") {
+        "pub mod day_"
+    } else {
+        "pub mod y"
+    };
+    let line = format!("pub mod {};", module);
+    let mods = source
+        .lines()
+        .scan(0, |offset, l| {
+            let start = *offset;
+            *offset += l.len() + 1;
+            Some((start, l))
+        })
+        .filter(|(_, l)| l.starts_with(prefix) && l.ends_with(';'))
+        .collect::<Vec<_>>();
+    if mods.iter().any(|&(_, l)| l == line) {
+        return Err(ScaffoldError::Registered(format!("`{}`", module)));
+    }
+    let name = |l: &str| l["pub mod ".len()..].trim_end_matches(';').to_string();
+    let (at, blank) = match mods.iter().find(|&&(_, l)| name(l).as_str() > module) {
+        Some(&(start, _)) => (start, ""),
+        None => match mods.last() {
+            Some(&(start, l)) => (start + l.len() + 1, ""),
+            None if module.starts_with("day_") && source.starts_with("//!") => {
+                let header = source.find("\n\n").ok_or_else(|| no_registry(path))?;
+                (header + 2, "\n")
+            }
+            None => return Err(no_registry(path)),
+        },
+    };
+    Ok(format!(
+        "{}{}\n{}{}",
+        &source[..at],
+        line,
+        blank,
+        &source[at..]
+    ))
+}
+
+/// Lays out a slice of struct literals, each a list of fields and their
+/// values, as rustfmt would.
+fn slice_literal(ty: &str, entries: &[Vec<(&str, String)>]) -> String {
+    let literal = |fields: &[(&str, String)], indent: &str| {
+        let fields = fields
+            .iter()
+            .map(|(name, value)| format!("{}    {}: {},\n", indent, name, value))
+            .collect::<String>();
+        format!("{} {{\n{}{}}}", ty, fields, indent)
+    };
+    match entries {
+        // A single literal stays on the line of the brackets
+        [entry] => format!("&[{}]", literal(entry, "")),
+        _ if entries.is_empty() => "&[]".to_string(),
+        _ => {
+            let entries = entries
+                .iter()
+                .map(|entry| format!("    {},\n", literal(entry, "    ")))
+                .collect::<String>();
+            format!("&[\n{}]", entries)
+        }
+    }
+}
+
+/// Finds `pub const <name>: &[<ty>] = ...;` in `source` and the captures of
+/// `entry` for each of its elements. Fails unless every element matches.
+fn registry<'a>(
+    path: &Path,
+    source: &'a str,
+    name: &str,
+    ty: &str,
+    entry: &str,
+) -> Result<(std::ops::Range<usize>, Vec<regex::Captures<'a>>), ScaffoldError> {
+    let list = Regex::new(&format!(
+        r"(?s)pub const {}: &\[{}\] = &\[(.*?)\];\n",
+        name, ty
+    ))
+    .unwrap();
+    let found = list.captures(source).ok_or_else(|| no_registry(path))?;
+    let body = found.get(1).unwrap();
+    let entries = Regex::new(entry)
+        .unwrap()
+        .captures_iter(body.as_str())
+        .collect::<Vec<_>>();
+    if entries.len() != body.as_str().matches(&format!("{} {{", ty)).count() {
+        return Err(no_registry(path));
+    }
+    Ok((found.get(0).unwrap().range(), entries))
+}
+
+/// Adds the solver of `id` to `DAYS` in `source`, the module of `year` read
+/// from `path`, after the other solvers of its day so that it does not
+/// replace their default.
+pub fn add_day(path: &Path, source: &str, year: u32, id: &str) -> Result<String, ScaffoldError> {
+    let number = day_number(id)?;
+    let entry =
+        r#"Day \{\s*year: (\d+),\s*id: "([^"]+)",\s*number: (\d+),\s*solver: (&[\w:]+),\s*\}"#;
+    let (range, found) = registry(path, source, "DAYS", "Day", entry)?;
+    let mut days = found
+        .iter()
+        .map(|m| {
+            let number = m[3].parse::<u32>().unwrap_or(0);
+            let fields = vec![
+                ("year", m[1].to_string()),
+                ("id", format!("\"{}\"", &m[2])),
+                ("number", m[3].to_string()),
+                ("solver", m[4].to_string()),
+            ];
+            (m[2].to_string(), number, fields)
+        })
+        .collect::<Vec<_>>();
+    if days.iter().any(|(other, _, _)| other == id) {
+        return Err(ScaffoldError::Registered(format!("day `{}`", id)));
+    }
+    let at = days
+        .iter()
+        .position(|&(_, other, _)| other > number)
+        .unwrap_or(days.len());
+    let fields = vec![
+        ("year", year.to_string()),
+        ("id", format!("\"{}\"", id)),
+        ("number", number.to_string()),
+        ("solver", format!("&day_{}::{}", id, number_name(number))),
+    ];
+    days.insert(at, (id.to_string(), number, fields));
+
+    let days = days.into_iter().map(|(_, _, f)| f).collect::<Vec<_>>();
+    Ok(format!(
+        "{}pub const DAYS: &[Day] = {};\n{}",
+        &source[..range.start],
+        slice_literal("Day", &days),
+        &source[range.end..]
+    ))
+}
+
+/// Adds the module of `year` to `lib`, read from `path`, and its entry to
+/// `YEARS` in year order.
+pub fn add_year(path: &Path, lib: &str, year: u32) -> Result<String, ScaffoldError> {
+    let lib = add_module(path, lib, &format!("y{}", check_year(year)?))?;
+    let entry = r"Year \{\s*year: (\d+),\s*days: y\d+::DAYS,\s*\}";
+    let (range, found) = registry(path, &lib, "YEARS", "Year", entry)?;
+    let mut years = found
+        .iter()
+        .filter_map(|m| m[1].parse::<u32>().ok())
+        .collect::<Vec<_>>();
+    if years.contains(&year) {
+        return Err(ScaffoldError::Registered(format!("year {}", year)));
+    }
+    years.push(year);
+    years.sort_unstable();
+
+    let years = years
+        .iter()
+        .map(|year| {
+            vec![
+                ("year", year.to_string()),
+                ("days", format!("y{}::DAYS", year)),
+            ]
+        })
+        .collect::<Vec<_>>();
+    Ok(format!(
+        "{}pub const YEARS: &[Year] = {};\n{}",
+        &lib[..range.start],
+        slice_literal("Year", &years),
+        &lib[range.end..]
+    ))
+}
+
+/// Writes `src/y<year>/day_<id>.rs` under `root` from the template and
+/// registers it, creating the module of the year if needed. Nothing is
+/// written if the module exists or is already registered. Returns the path
+/// of the new module.
+pub fn create(root: &Path, year: u32, id: &str) -> Result<PathBuf, ScaffoldError> {
+    let source = render(year, id)?;
+    let dir = root.join(format!("src/y{}", year));
+    let path = dir.join(format!("day_{}.rs", id));
+    if path.exists() {
+        return Err(ScaffoldError::Exists(path));
+    }
+    let io = |path: &Path| {
+        let path = path.to_path_buf();
+        move |error| ScaffoldError::Io { path, error }
+    };
+    let registry = dir.join("mod.rs");
+    let new_year = !registry.exists();
+    let module = if new_year {
+        year_module(year)
+    } else {
+        std::fs::read_to_string(&registry).map_err(io(&registry))?
+    };
+    let module = add_module(&registry, &module, &format!("day_{}", id))?;
+    let module = add_day(&registry, &module, year, id)?;
+    let lib_path = root.join(LIB);
+    let lib = if new_year {
+        let lib = std::fs::read_to_string(&lib_path).map_err(io(&lib_path))?;
+        Some(add_year(&lib_path, &lib, year)?)
+    } else {
+        None
+    };
+
+    std::fs::create_dir_all(&dir).map_err(io(&dir))?;
+    std::fs::write(&path, source).map_err(io(&path))?;
+    std::fs::write(&registry, module).map_err(io(&registry))?;
+    if let Some(lib) = lib {
+        std::fs::write(&lib_path, lib).map_err(io(&lib_path))?;
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{add_day, add_module, add_year, create, day_number, number_name};
+    use crate::scaffold::{render, year_module, ScaffoldError, LIB};
+    use std::path::Path;
+
+    const MOD_RS: &str = concat!(
+        "//! Solutions for 2021.\n",
+        "\n",
+        "pub mod day_1;\n",
+        "pub mod day_24;\n",
+        "pub mod day_3;\n",
+        "\n",
+        "pub const DAYS: &[Day] = &[\n",
+        "    Day {\n",
+        "        year: 2021,\n",
+        "        id: \"1\",\n",
+        "        number: 1,\n",
+        "        solver: &day_1::One,\n",
+        "    },\n",
+        "    Day {\n",
+        "        year: 2021,\n",
+        "        id: \"3\",\n",
+        "        number: 3,\n",
+        "        solver: &day_3::Three,\n",
+        "    },\n",
+        "    Day {\n",
+        "        year: 2021,\n",
+        "        id: \"24\",\n",
+        "        number: 24,\n",
+        "        solver: &day_24::TwentyFour,\n",
+        "    },\n",
+        "];\n",
+    );
+
+    const LIB_RS: &str = concat!(
+        "pub mod grid;\n",
+        "\n",
+        "pub mod y2021;\n",
+        "\n",
+        "pub const YEARS: &[Year] = &[Year {\n",
+        "    year: 2021,\n",
+        "    days: y2021::DAYS,\n",
+        "}];\n",
+    );
+
+    #[test]
+    fn names() {
+        assert_eq!("Nine", number_name(9));
+        assert_eq!("Twenty", number_name(20));
+        assert_eq!("TwentyFive", number_name(25));
+        assert_eq!(24, day_number("24b").unwrap());
+        for id in &["0", "26", "07", "5bc", "b", "-1"] {
+            assert!(matches!(day_number(id), Err(ScaffoldError::InvalidDay(_))));
+        }
+
+        let source = render(2021, "21b").unwrap();
+        assert!(source.contains("pub struct TwentyOne;"));
+        assert!(source.contains("twentyone_impl(input, true)"));
+        assert!(source.contains("use crate::y2021::day_21b::parse;"));
+        assert!(!source.contains("{{"));
+        assert!(matches!(
+            render(21, "1"),
+            Err(ScaffoldError::InvalidYear(21))
+        ));
+    }
+
+    #[test]
+    fn registration() {
+        let path = Path::new("mod.rs");
+        let module = add_module(path, MOD_RS, "day_2").unwrap();
+        assert!(module.contains("pub mod day_1;\npub mod day_2;\npub mod day_24;\n"));
+        let module = add_module(path, &module, "day_9").unwrap();
+        assert!(module.contains("pub mod day_3;\npub mod day_9;\n\n"));
+        assert!(matches!(
+            add_module(path, &module, "day_3"),
+            Err(ScaffoldError::Registered(_))
+        ));
+
+        let module = add_day(path, MOD_RS, 2021, "2").unwrap();
+        assert!(module.contains(concat!(
+            "        solver: &day_1::One,\n",
+            "    },\n",
+            "    Day {\n",
+            "        year: 2021,\n",
+            "        id: \"2\",\n",
+            "        number: 2,\n",
+            "        solver: &day_2::Two,\n",
+            "    },\n",
+            "    Day {\n",
+            "        year: 2021,\n",
+            "        id: \"3\",\n",
+        )));
+        // A second solver goes after the default
+        let module = add_day(path, &module, 2021, "3b").unwrap();
+        assert!(module
+            .contains("::Three,\n    },\n    Day {\n        year: 2021,\n        id: \"3b\","));
+        let module = add_day(path, &module, 2021, "25").unwrap();
+        assert!(module.ends_with("        solver: &day_25::TwentyFive,\n    },\n];\n"));
+        assert!(matches!(
+            add_day(path, &module, 2021, "24"),
+            Err(ScaffoldError::Registered(_))
+        ));
+        assert!(matches!(
+            add_day(path, "pub mod day_1;\n", 2021, "2"),
+            Err(ScaffoldError::NoRegistry(_))
+        ));
+    }
+
+    #[test]
+    fn years() {
+        let path = Path::new("mod.rs");
+        let module = add_module(path, &year_module(2022), "day_1").unwrap();
+        let module = add_day(path, &module, 2022, "1").unwrap();
+        assert!(module.starts_with("//! Solutions for 2022.\n\npub mod day_1;\n\nuse crate"));
+        assert!(module.ends_with(concat!(
+            "pub const DAYS: &[Day] = &[Day {\n",
+            "    year: 2022,\n",
+            "    id: \"1\",\n",
+            "    number: 1,\n",
+            "    solver: &day_1::One,\n",
+            "}];\n",
+        )));
+        assert!(add_day(path, &module, 2022, "2")
+            .unwrap()
+            .contains("&[\n    Day {\n        year: 2022,\n        id: \"1\",\n"));
+
+        let path = Path::new(LIB);
+        let lib = add_year(path, LIB_RS, 2020).unwrap();
+        assert_eq!(
+            concat!(
+                "pub mod grid;\n",
+                "\n",
+                "pub mod y2020;\n",
+                "pub mod y2021;\n",
+                "\n",
+                "pub const YEARS: &[Year] = &[\n",
+                "    Year {\n",
+                "        year: 2020,\n",
+                "        days: y2020::DAYS,\n",
+                "    },\n",
+                "    Year {\n",
+                "        year: 2021,\n",
+                "        days: y2021::DAYS,\n",
+                "    },\n",
+                "];\n",
+            ),
+            lib
+        );
+        assert!(matches!(
+            add_year(path, LIB_RS, 2021),
+            Err(ScaffoldError::Registered(_))
+        ));
+    }
+
+    #[test]
+    fn creates() {
+        let root = std::env::temp_dir().join(format!("advent-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src/y2021")).unwrap();
+        std::fs::write(root.join(LIB), LIB_RS).unwrap();
+        std::fs::write(root.join("src/y2021/mod.rs"), MOD_RS).unwrap();
+
+        let path = create(&root, 2021, "2").unwrap();
+        assert_eq!(root.join("src/y2021/day_2.rs"), path);
+        assert_eq!(
+            render(2021, "2").unwrap(),
+            std::fs::read_to_string(&path).unwrap()
+        );
+        let module = std::fs::read_to_string(root.join("src/y2021/mod.rs")).unwrap();
+        assert!(module.contains("pub mod day_2;") && module.contains("&day_2::Two"));
+        assert_eq!(LIB_RS, std::fs::read_to_string(root.join(LIB)).unwrap());
+
+        std::fs::write(&path, "// edited\n").unwrap();
+        assert!(matches!(
+            create(&root, 2021, "2"),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert_eq!("// edited\n", std::fs::read_to_string(&path).unwrap());
+        assert_eq!(
+            module,
+            std::fs::read_to_string(root.join("src/y2021/mod.rs")).unwrap()
+        );
+
+        create(&root, 2022, "1").unwrap();
+        assert!(root.join("src/y2022/day_1.rs").exists());
+        let lib = std::fs::read_to_string(root.join(LIB)).unwrap();
+        assert!(lib.contains("pub mod y2021;\npub mod y2022;\n"));
+        assert!(lib.contains("days: y2022::DAYS"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+use crate::answer::Answer;
+use crate::parsing::ParseError;
+use std::any::Any;
+
+/// A day's puzzle: the input is parsed once and both parts are solved from
+/// the parsed form. Malformed input is reported by `parse` rather than by a
+/// panic in the parts.
+pub trait Solution {
+    type Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Self::Parsed, ParseError>;
+    fn part1(input: &Self::Parsed) -> Answer;
+    fn part2(input: &Self::Parsed) -> Answer;
+}
+
+/// Object-safe view of a `Solution`, so days with different `Parsed` types can
+/// share one registry.
+pub trait Solver: Sync {
+    fn parse(&self, input: &[String]) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, parsed: &dyn Any) -> Answer;
+    fn part2(&self, parsed: &dyn Any) -> Answer;
+}
+
+impl<T> Solver for T
+where
+    T: Solution + Sync,
+    T::Parsed: 'static,
+{
+    fn parse(&self, input: &[String]) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(T::parse(input)?))
+    }
+
+    fn part1(&self, parsed: &dyn Any) -> Answer {
+        T::part1(parsed.downcast_ref().expect("parsed by another day"))
+    }
+
+    fn part2(&self, parsed: &dyn Any) -> Answer {
+        T::part2(parsed.downcast_ref().expect("parsed by another day"))
+    }
+}
+
+/// An entry in the day registry.
+pub struct Day {
+    pub year: u32,
+    /// Id used on the command line, e.g. `15` or `24b`.
+    pub id: &'static str,
+    pub number: u32,
+    pub solver: &'static dyn Solver,
+}
+
+impl Day {
+    /// Identifies the day across years in answers and baseline files, e.g.
+    /// `2021/24b`.
+    pub fn key(&self) -> String {
+        format!("{}/{}", self.year, self.id)
+    }
+}
+
+/// The days of one year.
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::parsing::{lines, ParseError};
+    use crate::solution::{Solution, Solver};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<i32>;
+
+        fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<i32>, ParseError> {
+            lines(input).map(|line| line.num(line.text)).collect()
+        }
+
+        fn part1(input: &Vec<i32>) -> Answer {
+            input.iter().sum::<i32>().into()
+        }
+
+        fn part2(input: &Vec<i32>) -> Answer {
+            input.len().into()
+        }
+    }
+
+    #[test]
+    fn solver() {
+        let solver: &dyn Solver = &Sum;
+        let parsed = solver.parse(&["1".to_string(), "2".to_string()]).unwrap();
+        assert_eq!(Answer::Int(3), solver.part1(&*parsed));
+        assert_eq!(Answer::Int(2), solver.part2(&*parsed));
+        let err = solver.parse(&["1".to_string(), "x".to_string()]).err();
+        assert_eq!(Some(2), err.map(|e| e.line));
+    }
+}
@@ -0,0 +1,208 @@
+//! An unbounded grid that stores only the cells that were set, for puzzles
+//! whose area grows, is mostly empty, or has no fixed size.
+
+use crate::grid::Grid;
+use crate::point::Point2;
+use std::collections::HashMap;
+use std::fmt;
+
+/// `bounds` grown to hold `p`.
+fn widen(bounds: Option<(Point2, Point2)>, p: Point2) -> (Point2, Point2) {
+    match bounds {
+        None => (p, p),
+        Some((min, max)) => (
+            Point2::new(min.x.min(p.x), min.y.min(p.y)),
+            Point2::new(max.x.max(p.x), max.y.max(p.y)),
+        ),
+    }
+}
+
+/// Cells at signed coordinates on a background of `default`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseGrid<Cell> {
+    default: Cell,
+    cells: HashMap<Point2, Cell>,
+    /// The top left and bottom right corners of the set cells.
+    bounds: Option<(Point2, Point2)>,
+}
+
+impl<Cell> SparseGrid<Cell> {
+    pub fn new(default: Cell) -> SparseGrid<Cell> {
+        SparseGrid {
+            default,
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// The grid of the cells of `grid` that differ from `default`, with the
+    /// top left corner of `grid` at the origin.
+    pub fn from_grid(grid: &Grid<Cell>, default: Cell) -> SparseGrid<Cell>
+    where
+        Cell: Clone + PartialEq,
+    {
+        let mut sparse = SparseGrid::new(default);
+        for (y, row) in grid.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if *cell != sparse.default {
+                    sparse.set(Point2::from((x, y)), cell.clone());
+                }
+            }
+        }
+        sparse
+    }
+
+    /// The value of every cell that was not set.
+    pub fn default(&self) -> &Cell {
+        &self.default
+    }
+
+    /// The cell at `p`, or the default if it was not set.
+    pub fn get(&self, p: Point2) -> &Cell {
+        self.cells.get(&p).unwrap_or(&self.default)
+    }
+
+    /// Whether the cell at `p` was set, even if to the default.
+    pub fn contains(&self, p: Point2) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    /// Sets the cell at `p`, returning the previous one if it was set.
+    pub fn set(&mut self, p: Point2, cell: Cell) -> Option<Cell> {
+        self.bounds = Some(widen(self.bounds, p));
+        self.cells.insert(p, cell)
+    }
+
+    /// Resets the cell at `p` to the default, returning it if it was set.
+    pub fn remove(&mut self, p: Point2) -> Option<Cell> {
+        let prev = self.cells.remove(&p)?;
+        if self
+            .bounds
+            .is_some_and(|(min, max)| p.x == min.x || p.y == min.y || p.x == max.x || p.y == max.y)
+        {
+            self.bounds = self
+                .cells
+                .keys()
+                .fold(None, |bounds, &p| Some(widen(bounds, p)));
+        }
+        Some(prev)
+    }
+
+    /// The number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle
+    /// holding every set cell, or `None` if no cell is set.
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        self.bounds
+    }
+
+    /// The set cells and their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &Cell)> {
+        self.cells.iter().map(|(&p, cell)| (p, cell))
+    }
+
+    /// The cells inside the bounds as a `Grid` whose top left corner is the
+    /// top left corner of the bounds.
+    pub fn to_grid(&self) -> Grid<Cell>
+    where
+        Cell: Clone,
+    {
+        match self.bounds {
+            None => Grid::new(vec![]),
+            Some((min, max)) => {
+                let size = max - min + Point2::new(1, 1);
+                Grid::new_with(size.x as usize, size.y as usize, |x, y| {
+                    self.get(min + Point2::from((x, y))).clone()
+                })
+            }
+        }
+    }
+
+    /// Draws the cells inside the bounds like [`Grid::to_string_with`].
+    pub fn to_string_with<F: Fn(&Cell) -> char>(&self, f: F) -> String
+    where
+        Cell: Clone,
+    {
+        self.to_grid().to_string_with(f)
+    }
+}
+
+/// Draws the cells inside the bounds like a `Grid`, rulers and all.
+impl<Cell: Clone + fmt::Display> fmt::Display for SparseGrid<Cell> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_grid(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+    use crate::point::Point2;
+    use crate::sparse_grid::SparseGrid;
+
+    #[test]
+    fn cells() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(None, grid.bounds());
+        assert_eq!(&'.', grid.get(Point2::new(-5, 3)));
+        assert_eq!(None, grid.set(Point2::new(-2, 1), '#'));
+        assert_eq!(None, grid.set(Point2::new(3, -1), '#'));
+        assert_eq!(Some('#'), grid.set(Point2::new(3, -1), 'o'));
+        assert_eq!(&'o', grid.get(Point2::new(3, -1)));
+        assert_eq!(2, grid.len());
+        assert_eq!(
+            Some((Point2::new(-2, -1), Point2::new(3, 1))),
+            grid.bounds()
+        );
+
+        let mut cells = grid.iter().collect::<Vec<_>>();
+        cells.sort();
+        assert_eq!(
+            vec![(Point2::new(-2, 1), &'#'), (Point2::new(3, -1), &'o')],
+            cells
+        );
+
+        assert_eq!(Some('o'), grid.remove(Point2::new(3, -1)));
+        assert_eq!(None, grid.remove(Point2::new(3, -1)));
+        assert_eq!(
+            Some((Point2::new(-2, 1), Point2::new(-2, 1))),
+            grid.bounds()
+        );
+        grid.remove(Point2::new(-2, 1));
+        assert!(grid.is_empty());
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[test]
+    fn conversions() {
+        let dense = Grid::new(vec![vec!['.', '#', '.'], vec!['.', '.', '#']]);
+        let sparse = SparseGrid::from_grid(&dense, '.');
+        assert_eq!(2, sparse.len());
+        assert_eq!(&'#', sparse.get(Point2::new(2, 1)));
+        assert_eq!(
+            Grid::new(vec![vec!['#', '.'], vec!['.', '#']]),
+            sparse.to_grid()
+        );
+        assert_eq!("#.\n.#\n", sparse.to_string());
+        assert_eq!(
+            "10\n01\n",
+            sparse.to_string_with(|&c| if c == '#' { '1' } else { '0' })
+        );
+        assert_eq!("", SparseGrid::new(0).to_string());
+
+        let mut sparse = SparseGrid::new(0);
+        sparse.set(Point2::new(-3, -3), 1);
+        sparse.set(Point2::new(7, 8), 2);
+        let ruled = format!("{:#}", sparse);
+        assert!(ruled.starts_with("1000000000|0\n"));
+        assert!(ruled.contains("\n-\n"));
+        assert!(ruled.ends_with("\n0000000000|2\n"));
+    }
+}
@@ -1,24 +1,15 @@
-#[allow(dead_code)]
-pub fn as_groups(lines: Vec<String>) -> Vec<Vec<String>> {
-    let mut grouped = Vec::new();
-    let mut groups = Vec::new();
-    for line in lines {
-        if line.is_empty() && !grouped.is_empty() {
-            groups.push(grouped);
-            grouped = Vec::new();
-        } else {
-            grouped.push(line);
-        }
-    }
-
-    if !grouped.is_empty() {
-        groups.push(grouped);
-    }
+use crate::parsing;
 
-    groups
+/// Splits `lines` into groups separated by blank lines.
+#[deprecated(note = "use `parsing::sections`")]
+pub fn as_groups(lines: Vec<String>) -> Vec<Vec<String>> {
+    parsing::sections(&lines)
+        .into_iter()
+        .map(|section| section.iter().map(|line| line.text.to_string()).collect())
+        .collect()
 }
 
-#[allow(unused_macros)]
+#[macro_export]
 macro_rules! collection {
     // map-like
     ($($k:expr => $v:expr),* $(,)?) => {{
@@ -33,3 +24,16 @@ macro_rules! collection {
         Iterator::collect::<HashSet<_>>(IntoIterator::into_iter([$($v,)*]))
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[allow(deprecated)]
+    fn as_groups() {
+        let lines = ["a", "b", "", "", "c"].iter().map(|s| s.to_string());
+        assert_eq!(
+            vec![vec!["a", "b"], vec!["c"]],
+            crate::utils::as_groups(lines.collect())
+        );
+    }
+}
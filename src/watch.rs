@@ -0,0 +1,128 @@
+//! Polling files for changes, and remembering the answers of the previous
+//! run so that `watch` can point out the ones that changed.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// What is compared between polls: modification time and length, or `None`
+/// for a missing file.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &std::path::Path) -> Stamp {
+    let meta = std::fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/// A set of files and directories that are checked for changes by polling.
+pub struct Watch {
+    files: Vec<PathBuf>,
+    /// Directories whose files are watched, including ones added later.
+    dirs: Vec<PathBuf>,
+    last: Option<Vec<(PathBuf, Stamp)>>,
+}
+
+impl Watch {
+    pub fn new(files: Vec<PathBuf>, dirs: Vec<PathBuf>) -> Watch {
+        Watch {
+            files,
+            dirs,
+            last: None,
+        }
+    }
+
+    fn stamps(&self) -> Vec<(PathBuf, Stamp)> {
+        let mut paths = self.files.clone();
+        for dir in &self.dirs {
+            let mut entries = std::fs::read_dir(dir)
+                .into_iter()
+                .flatten()
+                .filter_map(|e| Some(e.ok()?.path()))
+                .filter(|path| path.is_file())
+                .collect::<Vec<_>>();
+            entries.sort();
+            paths.extend(entries);
+        }
+        paths
+            .into_iter()
+            .map(|path| {
+                let stamp = stamp(&path);
+                (path, stamp)
+            })
+            .collect()
+    }
+
+    /// Whether any file was modified, created or removed since the last
+    /// call. The first call always reports a change.
+    pub fn changed(&mut self) -> bool {
+        let stamps = self.stamps();
+        let changed = self.last.as_ref() != Some(&stamps);
+        self.last = Some(stamps);
+        changed
+    }
+}
+
+/// How an answer compares to the one in the previous run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// There was no previous run, or it had no answer under this key.
+    New,
+    Same,
+    /// The answer differs from `previous`.
+    Changed(String),
+}
+
+/// The answers of the previous run, by a key such as `ex1 part 2`.
+#[derive(Clone, Debug, Default)]
+pub struct History(HashMap<String, String>);
+
+impl History {
+    /// Records `answer` under `key` and compares it to the previous one.
+    pub fn update(&mut self, key: &str, answer: &str) -> Change {
+        match self.0.insert(key.to_string(), answer.to_string()) {
+            None => Change::New,
+            Some(previous) if previous == answer => Change::Same,
+            Some(previous) => Change::Changed(previous),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::watch::{Change, History, Watch};
+
+    #[test]
+    fn polling() {
+        let dir = std::env::temp_dir().join(format!("advent-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("examples")).unwrap();
+        let input = dir.join("input.txt");
+        let mut watch = Watch::new(vec![input.clone()], vec![dir.join("examples")]);
+        assert!(watch.changed());
+        assert!(!watch.changed());
+
+        std::fs::write(&input, "1\n").unwrap();
+        assert!(watch.changed());
+        assert!(!watch.changed());
+        std::fs::write(&input, "1\n2\n").unwrap();
+        assert!(watch.changed());
+
+        std::fs::write(dir.join("examples/ex1.txt"), "3\n").unwrap();
+        assert!(watch.changed());
+        std::fs::remove_file(dir.join("examples/ex1.txt")).unwrap();
+        assert!(watch.changed());
+        assert!(!watch.changed());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn history() {
+        let mut history = History::default();
+        assert_eq!(Change::New, history.update("part 1", "40"));
+        assert_eq!(Change::Same, history.update("part 1", "40"));
+        assert_eq!(
+            Change::Changed("40".to_string()),
+            history.update("part 1", "41")
+        );
+        assert_eq!(Change::New, history.update("ex1 part 1", "41"));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::answer::Answer;
+use crate::parsing::{lines, ParseError};
+use crate::solution::Solution;
+
+pub fn one_impl(input: &[i32]) -> usize {
+    let mut prev = None;
+    let mut res = 0;
+    for item in input {
+        if let Some(p) = prev {
+            if p < item {
+                res += 1;
+            }
+        }
+        prev = Some(item);
+    }
+    res
+}
+
+pub fn two_window(input: &[i32]) -> Vec<i32> {
+    let mut res = Vec::new();
+    for i in 2..input.len() {
+        res.push(input[(i - 2)..=i].iter().sum())
+    }
+    res
+}
+
+pub struct One;
+
+impl Solution for One {
+    type Parsed = Vec<i32>;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<i32>, ParseError> {
+        lines(input).map(|line| line.num(line.text)).collect()
+    }
+
+    fn part1(input: &Vec<i32>) -> Answer {
+        one_impl(input).into()
+    }
+
+    fn part2(input: &Vec<i32>) -> Answer {
+        one_impl(&two_window(input)).into()
+    }
+}
@@ -1,12 +1,13 @@
+use crate::answer::Answer;
+use crate::parsing::{self, lines};
+use crate::solution::Solution;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 use std::iter::Peekable;
 
-type Parsed = Vec<Vec<char>>;
+pub type Parsed = Vec<Vec<char>>;
 
+#[allow(dead_code)]
 enum Chunk {
     Paren(Vec<Chunk>),
     Square(Vec<Chunk>),
@@ -59,7 +60,7 @@ fn parse_tree<I: Iterator<Item = char>>(
     parse_tree(res, input, expected)
 }
 
-fn ten_impl(input: &[Vec<char>], day_2: bool) -> usize {
+pub fn ten_impl(input: &[Vec<char>], day_2: bool) -> usize {
     if !day_2 {
         let mut counts: HashMap<char, usize> = HashMap::new();
         for row in input {
@@ -101,43 +102,31 @@ fn ten_impl(input: &[Vec<char>], day_2: bool) -> usize {
     counts[counts.len() / 2]
 }
 
-fn parse<S: AsRef<str>>(input: &[S]) -> Parsed {
-    input
-        .iter()
-        .map(|s| s.as_ref().chars().collect())
-        .collect::<Vec<Vec<_>>>()
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, parsing::ParseError> {
+    lines(input)
+        .map(|line| {
+            line.chars("a bracket", |c| match c {
+                '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' => Some(c),
+                _ => None,
+            })
+        })
+        .collect()
 }
 
-pub fn ten() -> Result<(), std::io::Error> {
-    let file = File::open("10_input")?;
-    let reader = BufReader::new(file);
-    let lines = parse(&reader.lines().map(|s| s.unwrap()).collect::<Vec<_>>());
-    let res = ten_impl(&lines, false);
-    println!("Day 10 part 1: {}", res);
-    let res_2 = ten_impl(&lines, true);
-    println!("Day 10 part 2: {}", res_2);
-    Ok(())
-}
+pub struct Ten;
+
+impl Solution for Ten {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, parsing::ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        ten_impl(input, false).into()
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::day_10::{parse, ten_impl};
-
-    #[test]
-    fn it_works() {
-        let lines = vec![
-            "[({(<(())[]>[[{[]{<()<>>",
-            "[(()[<>])]({[<{<<[]>>(",
-            "{([(<{}[<>[]}>{[]{[(<()>",
-            "(((({<>}<{<{<>}{[]{[]{}",
-            "[[<[([]))<([[{}[[()]]]",
-            "[{[{({}]{}}([{[{{{}}([]",
-            "{<[[]]>}<{[{[{[]{()[[[]",
-            "[<(<(<(<{}))><([]([]()",
-            "<{([([[(<>()){}]>(<<{{",
-            "<{([{{}}[<[[[<>{}]]]>[]]",
-        ];
-        assert_eq!(26397, ten_impl(&parse(&lines), false));
-        assert_eq!(288957, ten_impl(&parse(&lines), true));
+    fn part2(input: &Parsed) -> Answer {
+        ten_impl(input, true).into()
     }
 }
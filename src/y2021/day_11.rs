@@ -0,0 +1,115 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parsing::{digit_grid, ParseError};
+use crate::solution::Solution;
+
+pub type Parsed = Grid<usize>;
+
+fn increase(grid: &mut Grid<usize>, x: usize, y: usize) {
+    grid.set(x, y, grid.get(x, y).unwrap() + 1);
+}
+
+fn flash(
+    grid: &mut Grid<usize>,
+    flashed: &mut Grid<bool>,
+    x: usize,
+    y: usize,
+    flash_count: &mut usize,
+) {
+    if *grid.get(x, y).unwrap() <= 9 || *flashed.get(x, y).unwrap() {
+        return;
+    }
+    flashed.set(x, y, true);
+    *flash_count += 1;
+    for (nx, ny) in grid.diag_nbors(x, y) {
+        increase(grid, nx, ny);
+        flash(grid, flashed, nx, ny, flash_count);
+    }
+}
+
+pub fn eleven_impl(input: &Parsed, day_2: bool) -> usize {
+    let mut grid = input.clone();
+    if !day_2 {
+        let steps = 100;
+        let mut flash_count = 0;
+        for _ in 0..steps {
+            let mut flashed = grid.map(|_| false);
+
+            for y in 0..grid.height() {
+                for x in 0..grid.width() {
+                    increase(&mut grid, x, y);
+                }
+            }
+
+            for y in 0..grid.height() {
+                for x in 0..grid.width() {
+                    flash(&mut grid, &mut flashed, x, y, &mut flash_count);
+                }
+            }
+
+            for y in 0..grid.height() {
+                for x in 0..grid.width() {
+                    if *grid.get(x, y).unwrap() > 9 {
+                        grid.set(x, y, 0);
+                    }
+                }
+            }
+        }
+
+        return flash_count;
+    }
+
+    let mut step = 1;
+    loop {
+        let mut flashed = grid.map(|_| false);
+        let mut flash_count = 0;
+
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                increase(&mut grid, x, y);
+            }
+        }
+
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                flash(&mut grid, &mut flashed, x, y, &mut flash_count);
+            }
+        }
+
+        if flash_count == grid.width() * grid.height() {
+            return step;
+        }
+
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                if *grid.get(x, y).unwrap() > 9 {
+                    grid.set(x, y, 0);
+                }
+            }
+        }
+
+        step += 1;
+    }
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    digit_grid(input)
+}
+
+pub struct Eleven;
+
+impl Solution for Eleven {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        eleven_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        eleven_impl(input, true).into()
+    }
+}
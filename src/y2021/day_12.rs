@@ -0,0 +1,98 @@
+use crate::answer::Answer;
+use crate::parsing::{lines, ParseError};
+use crate::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+pub type Parsed = HashMap<String, HashSet<String>>;
+
+fn recur(map: &Parsed, visited: &mut HashSet<String>, cur: &str) -> usize {
+    if cur == "start" {
+        return 1;
+    }
+    if visited.contains(cur) {
+        return 0;
+    }
+    if cur.chars().next().unwrap().is_ascii_lowercase() {
+        visited.insert(cur.to_string());
+    }
+    let mut routes = 0;
+    for edge in &map[cur] {
+        routes += recur(map, visited, edge);
+    }
+    visited.remove(cur);
+    routes
+}
+
+fn push(visited: &mut HashMap<String, bool>, cur: &str) {
+    if visited.contains_key(cur) {
+        visited.insert(cur.to_string(), true);
+    } else if cur.chars().next().unwrap().is_ascii_lowercase() {
+        visited.insert(cur.to_string(), false);
+    }
+}
+
+fn pop(visited: &mut HashMap<String, bool>, cur: &str) {
+    if visited.get(cur) == Some(&true) {
+        visited.insert(cur.to_string(), false);
+    } else {
+        visited.remove(cur);
+    }
+}
+
+fn recur2(map: &Parsed, visited: &mut HashMap<String, bool>, cur: &str) -> usize {
+    if cur == "start" {
+        return 1;
+    }
+    if visited.contains_key(cur)
+        && (cur == "start" || cur == "end" || visited.values().any(|v| v == &true))
+    {
+        return 0;
+    }
+
+    push(visited, cur);
+    let mut routes = 0;
+    for edge in &map[cur] {
+        routes += recur2(map, visited, edge);
+    }
+    pop(visited, cur);
+
+    routes
+}
+
+pub fn twelve_impl(input: &Parsed, day_2: bool) -> usize {
+    if !day_2 {
+        return recur(input, &mut HashSet::new(), "end");
+    }
+
+    recur2(input, &mut HashMap::new(), "end")
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let mut map: Parsed = HashMap::new();
+    for line in lines(input) {
+        let mut pair = line.text.split('-');
+        let start = line.field(pair.next(), "a cave")?.to_string();
+        let end = line.field(pair.next(), "`-` and a cave")?.to_string();
+        map.entry(start.clone()).or_default().insert(end.clone());
+        map.entry(end).or_default().insert(start);
+    }
+    Ok(map)
+}
+
+pub struct Twelve;
+
+impl Solution for Twelve {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        twelve_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        twelve_impl(input, true).into()
+    }
+}
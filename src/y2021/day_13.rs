@@ -0,0 +1,89 @@
+use crate::answer::Answer;
+use crate::parsing::{section, sections, ParseError};
+use crate::point::Point2;
+use crate::solution::Solution;
+use crate::sparse_grid::SparseGrid;
+
+#[derive(Debug)]
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+pub type Parsed = (Vec<Point2>, Vec<Fold>);
+
+/// Where `point` ends up after folding the paper along `fold`.
+fn fold_point(point: Point2, fold: &Fold) -> Point2 {
+    match *fold {
+        Fold::X(x) if point.x > x as i32 => Point2::new(2 * x as i32 - point.x, point.y),
+        Fold::Y(y) if point.y > y as i32 => Point2::new(point.x, 2 * y as i32 - point.y),
+        _ => point,
+    }
+}
+
+pub fn thirteen_impl(input: &Parsed, day2: bool) -> Answer {
+    let mut paper = SparseGrid::new('.');
+    for &point in &input.0 {
+        paper.set(point, '#');
+    }
+    for fold in &input.1 {
+        let mut folded = SparseGrid::new('.');
+        for (point, &dot) in paper.iter() {
+            folded.set(fold_point(point, fold), dot);
+        }
+
+        paper = folded;
+        if !day2 {
+            return paper.len().into();
+        }
+    }
+    paper.to_grid().into()
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let sections = sections(input);
+    let points = section(input, &sections, 0, "the dots")?
+        .iter()
+        .map(|line| match line.ints::<usize>(',')?[..] {
+            [x, y] => Ok(Point2::from((x, y))),
+            _ => Err(line.error(line.text, "x,y")),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let folds = section(input, &sections, 1, "the folds")?
+        .iter()
+        .map(|line| {
+            let fold = line
+                .text
+                .strip_prefix("fold along ")
+                .ok_or_else(|| line.error(line.text, "`fold along`"))?;
+            let (axis, at) = fold
+                .split_once('=')
+                .ok_or_else(|| line.error_at_end("`=`"))?;
+            match axis {
+                "x" => Ok(Fold::X(line.num(at)?)),
+                "y" => Ok(Fold::Y(line.num(at)?)),
+                _ => Err(line.error(axis, "x or y")),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((points, folds))
+}
+
+pub struct Thirteen;
+
+impl Solution for Thirteen {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        thirteen_impl(input, false)
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        thirteen_impl(input, true)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::answer::Answer;
+use crate::parsing::{section, sections, ParseError};
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub type Parsed = (Vec<char>, Vec<Rule>);
+
+pub struct Rule {
+    from: (char, char),
+    to: char,
+}
+
+pub fn fourteen_impl(input: &Parsed, day_2: bool) -> usize {
+    let mut state: HashMap<(char, char), usize> = HashMap::new();
+
+    let mut prev = input.0[0];
+    for c in &input.0[1..] {
+        *state.entry((prev, *c)).or_default() += 1;
+        prev = *c;
+    }
+
+    let mut rules = HashMap::new();
+    for rule in &input.1 {
+        rules.insert(rule.from, rule.to);
+    }
+
+    let steps = if !day_2 { 10 } else { 40 };
+    for _ in 0..steps {
+        let mut next_state: HashMap<(char, char), usize> = HashMap::new();
+        for (pair, count) in state {
+            if let Some(to) = rules.get(&pair) {
+                *next_state.entry((pair.0, *to)).or_default() += count;
+                *next_state.entry((*to, pair.1)).or_default() += count;
+            } else {
+                *next_state.entry(pair).or_default() += count;
+            }
+        }
+        state = next_state;
+    }
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for ((a, b), count) in state {
+        *counts.entry(a).or_default() += count;
+        *counts.entry(b).or_default() += count;
+    }
+    let mut real_counts = counts
+        .iter()
+        .map(|(c, count)| (count / 2, c))
+        .collect::<Vec<_>>();
+    real_counts.sort();
+    real_counts.last().unwrap().0 - real_counts[1].0
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let sections = sections(input);
+    let template = section(input, &sections, 0, "the polymer template")?[0];
+    let start_state = std::iter::once('#')
+        .chain(template.text.chars())
+        .chain(std::iter::once('#'))
+        .collect();
+    let rules = section(input, &sections, 1, "the insertion rules")?
+        .iter()
+        .map(|line| {
+            let (from, to) = line
+                .text
+                .split_once(" -> ")
+                .ok_or_else(|| line.error_at_end("` -> ` and an element"))?;
+            match (&from.chars().collect::<Vec<_>>()[..], to.chars().next()) {
+                (&[a, b], Some(to)) => Ok(Rule { from: (a, b), to }),
+                (_, None) => Err(line.error(to, "an element")),
+                _ => Err(line.error(from, "a pair of two elements")),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((start_state, rules))
+}
+
+pub struct Fourteen;
+
+impl Solution for Fourteen {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        fourteen_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        fourteen_impl(input, true).into()
+    }
+}
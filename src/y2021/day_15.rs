@@ -0,0 +1,64 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parsing::{digit_grid, ParseError};
+use crate::point::Point2;
+use crate::solution::Solution;
+use std::collections::{BinaryHeap, HashSet};
+
+pub type Parsed = Grid<usize>;
+
+/// Dijkstra from the top left to the bottom right corner of `map`, where
+/// entering a cell costs its value.
+pub fn lowest_risk(map: &Grid<usize>) -> usize {
+    let mut visited = HashSet::new();
+    let mut queue = BinaryHeap::new();
+
+    queue.push((usize::MAX, 0, 0));
+    while let Some((c, x, y)) = queue.pop() {
+        if x == map.width() - 1 && y == map.height() - 1 {
+            return usize::MAX - c;
+        }
+        if visited.contains(&(x, y)) {
+            continue;
+        }
+        visited.insert((x, y));
+        for (nx, ny, risk) in map.nbor_cells(x, y, &Point2::ORTHOGONAL) {
+            queue.push((c - risk, nx, ny));
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn fifteen_impl(input: &Parsed, day_2: bool) -> usize {
+    if !day_2 {
+        return lowest_risk(input);
+    }
+    let w = input.width();
+    let h = input.height();
+    lowest_risk(&Grid::new_with(w * 5, h * 5, |x, y| {
+        ((input.get(x % w, y % h).unwrap() + x / w + y / h) - 1) % 9 + 1
+    }))
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    digit_grid(input)
+}
+
+pub struct Fifteen;
+
+impl Solution for Fifteen {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        fifteen_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        fifteen_impl(input, true).into()
+    }
+}
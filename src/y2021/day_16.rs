@@ -0,0 +1,197 @@
+use crate::answer::Answer;
+use crate::parsing::{lines, ParseError};
+use crate::solution::Solution;
+use std::iter::Peekable;
+
+pub type Parsed = Vec<Packet>;
+
+#[derive(Debug)]
+pub struct Packet {
+    id: u8,
+    version: u8,
+    content: PacketContent,
+}
+
+#[derive(Debug)]
+enum PacketContent {
+    Literal(usize),
+    Operator(Vec<Packet>),
+}
+
+impl Packet {
+    fn version_sum(&self) -> usize {
+        self.version as usize
+            + match &self.content {
+                PacketContent::Literal(_) => 0,
+                PacketContent::Operator(v) => v.iter().map(|p| p.version_sum()).sum(),
+            }
+    }
+
+    fn value(&self) -> usize {
+        match &self.content {
+            PacketContent::Literal(v) => *v,
+            PacketContent::Operator(ps) => match self.id {
+                0 => ps.iter().map(|p| p.value()).sum(),
+                1 => ps.iter().map(|p| p.value()).product(),
+                2 => ps.iter().map(|p| p.value()).min().unwrap(),
+                3 => ps.iter().map(|p| p.value()).max().unwrap(),
+                5 => {
+                    if ps[0].value() > ps[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                6 => {
+                    if ps[0].value() < ps[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                7 => {
+                    if ps[0].value() == ps[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                _ => unreachable!(),
+            },
+        }
+    }
+}
+
+fn read_num<I: Iterator<Item = bool>>(iter: &mut Peekable<I>, bitcount: usize) -> Option<usize> {
+    let mut res = 0;
+    for _ in 0..bitcount {
+        res = (res << 1) | iter.next()? as usize;
+    }
+    Some(res)
+}
+
+fn literal<I: Iterator<Item = bool>>(iter: &mut Peekable<I>) -> Option<(PacketContent, usize)> {
+    let mut num = 0;
+    let mut stop = !iter.next()?;
+    let mut read_bits = 0;
+    loop {
+        read_bits += 5;
+        num = (num << 4) | read_num(iter, 4)?;
+        if stop {
+            break;
+        }
+        stop = !iter.next()?;
+    }
+    Some((PacketContent::Literal(num), read_bits))
+}
+
+fn operator<I: Iterator<Item = bool>>(iter: &mut Peekable<I>) -> Option<(PacketContent, usize)> {
+    if !iter.next()? {
+        let subpacket_bits = read_num(iter, 15)?;
+        let mut read_bits = 1 + 15;
+        let mut res = Vec::new();
+        while read_bits - 12 < subpacket_bits {
+            let (packet, bits) = packet(iter)?;
+            res.push(packet);
+            read_bits += bits;
+        }
+        Some((PacketContent::Operator(res), read_bits))
+    } else {
+        let subpacket_count = read_num(iter, 11)?;
+        let mut read_bits = 1 + 11;
+        let mut res = Vec::new();
+        for _ in 0..subpacket_count {
+            let (packet, bits) = packet(iter)?;
+            res.push(packet);
+            read_bits += bits;
+        }
+        Some((PacketContent::Operator(res), read_bits))
+    }
+}
+
+/// Reads a packet and counts its bits, or returns `None` if the bits run
+/// out first.
+fn packet<I: Iterator<Item = bool>>(iter: &mut Peekable<I>) -> Option<(Packet, usize)> {
+    let version: u8 = read_num(iter, 3)? as u8;
+    let ty: u8 = read_num(iter, 3)? as u8;
+
+    let (content, read_bits) = match ty {
+        4 => literal(iter)?,
+        _ => operator(iter)?,
+    };
+
+    Some((
+        Packet {
+            id: ty,
+            version,
+            content,
+        },
+        read_bits + 6,
+    ))
+}
+
+pub fn sixteen_impl(input: &[Packet], day_2: bool) -> usize {
+    if !day_2 {
+        input.iter().map(|p| p.version_sum()).sum()
+    } else {
+        input[0].value()
+    }
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let mut bits = Vec::new();
+    let mut last = None;
+    for line in lines(input) {
+        for digit in line.digits(16)? {
+            bits.extend(format!("{:04b}", digit).chars().map(|c| c == '1'));
+        }
+        last = Some(line);
+    }
+    let last = last.ok_or_else(|| ParseError::missing(input, "a packet"))?;
+
+    let mut iter = bits.into_iter().peekable();
+    let mut res = Vec::new();
+    let mut cont = true;
+    while cont {
+        let (packet, _) = packet(&mut iter).ok_or_else(|| {
+            last.error_at_offset(last.text.len(), "the rest of a truncated packet")
+        })?;
+        res.push(packet);
+        cont = iter.peek() == Some(&true);
+    }
+    Ok(res)
+}
+
+pub struct Sixteen;
+
+impl Solution for Sixteen {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        sixteen_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        sixteen_impl(input, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day_16::parse;
+
+    #[test]
+    fn truncated() {
+        assert_eq!(1, parse(&["D2FE28"]).unwrap().len());
+        for input in ["1", "D2FE2", "38006F45291200"[..10].as_ref()] {
+            let err = parse(&[input]).unwrap_err();
+            assert_eq!((1, input.len() + 1), (err.line, err.column));
+            assert_eq!("the rest of a truncated packet", err.expected);
+        }
+        assert_eq!(1, parse::<&str>(&[]).unwrap_err().line);
+    }
+}
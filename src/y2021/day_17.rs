@@ -0,0 +1,96 @@
+use crate::answer::Answer;
+use crate::parsing::{Line, ParseError};
+use crate::point::Point2;
+use crate::solution::Solution;
+
+/// The corners of the target area with the smallest and the largest
+/// coordinates.
+pub type Parsed = (Point2, Point2);
+
+#[derive(Debug)]
+struct Body {
+    pos: Point2,
+    vel: Point2,
+}
+
+impl Body {
+    fn step(&mut self) {
+        self.pos += self.vel;
+        self.vel -= Point2::new(self.vel.x.signum(), 1);
+    }
+}
+
+fn simulate(vel: Point2, target: Parsed) -> Option<i32> {
+    let (min, max) = target;
+    let mut max_y = 0;
+    let mut body = Body {
+        pos: Point2::ZERO,
+        vel,
+    };
+    while body.pos.y >= min.y {
+        if (min.x..=max.x).contains(&body.pos.x) && (min.y..=max.y).contains(&body.pos.y) {
+            return Some(max_y);
+        }
+        body.step();
+        max_y = max_y.max(body.pos.y);
+    }
+
+    None
+}
+
+pub fn seventeen_impl(input: &Parsed) -> (i32, i32) {
+    let mut best = i32::MIN;
+    let mut count = 0;
+    let (min, max) = *input;
+    for xvel in (min.x.min(0) - 1)..(max.x.max(0) + 1) {
+        for yvel in (min.y.min(0) - 1)..1000 {
+            if let Some(h) = simulate(Point2::new(xvel, yvel), *input) {
+                best = best.max(h);
+                count += 1;
+            }
+        }
+    }
+    (best, count)
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let line = Line::at(input, 0, "the target area")?;
+    let area = line
+        .text
+        .strip_prefix("target area: ")
+        .ok_or_else(|| line.error(line.text, "`target area:`"))?;
+    let mut ranges = area.split(',');
+    let (x0, x1) = line.range(line.field(ranges.next(), "x=a..b")?, "x")?;
+    let (y0, y1) = line.range(line.field(ranges.next(), "y=a..b")?, "y")?;
+    Ok((Point2::new(x0, y0), Point2::new(x1, y1)))
+}
+
+pub struct Seventeen;
+
+impl Solution for Seventeen {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        seventeen_impl(input).0.into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        seventeen_impl(input).1.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point2;
+    use crate::y2021::day_17::simulate;
+
+    #[test]
+    fn simulation() {
+        let target = (Point2::new(20, -10), Point2::new(30, -5));
+        assert_eq!(Some(45), simulate(Point2::new(6, 9), target));
+    }
+}
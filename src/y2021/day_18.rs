@@ -1,10 +1,10 @@
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
-use std::iter::Peekable;
+use crate::answer::Answer;
+use crate::parsing::{lines, Line, ParseError};
+use crate::solution::Solution;
+use std::str::CharIndices;
 
 #[derive(Clone, Debug, PartialEq)]
-enum Number {
+pub enum Number {
     Num(u32),
     Pair(Box<Number>, Box<Number>),
 }
@@ -68,7 +68,7 @@ impl Number {
                 if *e >= 10 {
                     *self = Number::Pair(
                         Box::new(Number::Num(*e / 2)),
-                        Box::new(Number::Num((*e + 1) / 2)),
+                        Box::new(Number::Num((*e).div_ceil(2))),
                     );
                     true
                 } else {
@@ -93,28 +93,9 @@ impl Number {
     }
 }
 
-type Parsed = Vec<Vec<char>>;
-
-fn parse_nums<I: Iterator<Item = char>>(iter: &mut Peekable<I>) -> Box<Number> {
-    match iter.next() {
-        Some('[') => {
-            let left = parse_nums(iter);
-            assert_eq!(iter.next(), Some(','));
-            let right = parse_nums(iter);
-            assert_eq!(iter.next(), Some(']'));
-            Box::new(Number::Pair(left, right))
-        }
-        Some(c) => Box::new(Number::Num(c.to_digit(10).unwrap())),
-        None => unreachable!(),
-    }
-}
-
-fn eighteen_impl(input: &[Vec<char>], day_2: bool) -> u32 {
-    let mut nums = Vec::new();
-    for row in input {
-        nums.push(parse_nums(&mut row.iter().copied().peekable()));
-    }
+pub type Parsed = Vec<Box<Number>>;
 
+pub fn eighteen_impl(nums: &[Box<Number>], day_2: bool) -> u32 {
     if !day_2 {
         let mut res: Number = *nums[0].clone();
         for num in &nums[1..] {
@@ -141,27 +122,69 @@ fn eighteen_impl(input: &[Vec<char>], day_2: bool) -> u32 {
     best
 }
 
-fn parse<S: AsRef<str>>(input: &[S]) -> Parsed {
-    input.iter().map(|s| s.as_ref().chars().collect()).collect()
+/// Consumes `expected` from `chars`, or reports what was there instead.
+fn expect(line: &Line, chars: &mut CharIndices, expected: char) -> Result<(), ParseError> {
+    match chars.next() {
+        Some((_, c)) if c == expected => Ok(()),
+        Some((i, _)) => Err(line.error_at_offset(i, &format!("`{}`", expected))),
+        None => Err(line.error_at_offset(line.text.len(), &format!("`{}`", expected))),
+    }
 }
 
-pub fn eighteen() -> Result<(), std::io::Error> {
-    let file = File::open("18_input")?;
-    let reader = BufReader::new(file);
-    let lines = parse(&reader.lines().map(|s| s.unwrap()).collect::<Vec<_>>());
-    let res = eighteen_impl(&lines, false);
-    println!("Day 15 part 1: {}", res);
-    let res_2 = eighteen_impl(&lines, true);
-    println!("Day 15 part 2: {}", res_2);
-    Ok(())
+fn parse_number(line: &Line, chars: &mut CharIndices) -> Result<Box<Number>, ParseError> {
+    match chars.next() {
+        Some((_, '[')) => {
+            let left = parse_number(line, chars)?;
+            expect(line, chars, ',')?;
+            let right = parse_number(line, chars)?;
+            expect(line, chars, ']')?;
+            Ok(Box::new(Number::Pair(left, right)))
+        }
+        Some((i, c)) => c
+            .to_digit(10)
+            .map(|d| Box::new(Number::Num(d)))
+            .ok_or_else(|| line.error_at_offset(i, "a digit or `[`")),
+        None => Err(line.error_at_offset(line.text.len(), "a digit or `[`")),
+    }
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    lines(input)
+        .map(|line| {
+            let mut chars = line.text.char_indices();
+            let num = parse_number(&line, &mut chars)?;
+            match chars.next() {
+                Some((i, _)) => Err(line.error_at_offset(i, "the end of the line")),
+                None => Ok(num),
+            }
+        })
+        .collect()
+}
+
+pub struct Eighteen;
+
+impl Solution for Eighteen {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        eighteen_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        eighteen_impl(input, true).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day_18::{eighteen_impl, parse, parse_nums, Number};
+    use crate::y2021::day_18::{parse, Number};
 
     fn pn(s: &str) -> Box<Number> {
-        parse_nums(&mut s.chars().peekable())
+        parse(&[s]).unwrap().remove(0)
     }
 
     fn p(l: Number, r: Number) -> Number {
@@ -200,42 +223,15 @@ mod tests {
     }
 
     #[test]
-    fn it_works() {
-        assert_eq!(
-            4140,
-            eighteen_impl(
-                &parse(&vec![
-                    "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
-                    "[[[5,[2,8]],4],[5,[[9,9],0]]]",
-                    "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
-                    "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
-                    "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
-                    "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
-                    "[[[[5,4],[7,7]],8],[[8,3],8]]",
-                    "[[9,3],[[9,9],[6,[4,9]]]]",
-                    "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
-                    "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
-                ]),
-                false
-            )
-        );
-        assert_eq!(
-            3993,
-            eighteen_impl(
-                &parse(&vec![
-                    "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
-                    "[[[5,[2,8]],4],[5,[[9,9],0]]]",
-                    "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
-                    "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
-                    "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
-                    "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
-                    "[[[[5,4],[7,7]],8],[[8,3],8]]",
-                    "[[9,3],[[9,9],[6,[4,9]]]]",
-                    "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
-                    "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
-                ]),
-                true
-            )
-        );
+    fn errors() {
+        let err = |s: &str| {
+            let err = parse(&["[1,2]", s]).unwrap_err();
+            (err.line, err.column, err.expected)
+        };
+        assert_eq!((2, 9, "`]`".to_string()), err("[[1,2],3"));
+        assert_eq!((2, 3, "`,`".to_string()), err("[1]"));
+        assert_eq!((2, 2, "a digit or `[`".to_string()), err("[x,1]"));
+        assert_eq!((2, 6, "the end of the line".to_string()), err("[1,2]]"));
+        assert_eq!((2, 1, "a digit or `[`".to_string()), err(""));
     }
 }
@@ -0,0 +1,192 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::parsing::{sections, ParseError};
+use crate::point::Point3;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+pub type Parsed = Vec<Vec<Point3>>;
+type Rotation = u8;
+
+fn flip(coord: Point3, rotation: Rotation) -> Point3 {
+    assert!(rotation < 24);
+
+    let Point3 { x, y, z } = coord;
+
+    match rotation {
+        0 => Point3::new(x, y, z),
+        1 => Point3::new(-y, x, z),
+        2 => Point3::new(-x, -y, z),
+        3 => Point3::new(y, -x, z),
+
+        4 => Point3::new(z, y, -x),
+        5 => Point3::new(z, x, y),
+        6 => Point3::new(z, -y, x),
+        7 => Point3::new(z, -x, -y),
+
+        8 => Point3::new(-x, y, -z),
+        9 => Point3::new(-y, -x, -z),
+        10 => Point3::new(x, -y, -z),
+        11 => Point3::new(y, x, -z),
+
+        12 => Point3::new(-z, y, x),
+        13 => Point3::new(-z, x, -y),
+        14 => Point3::new(-z, -y, -x),
+        15 => Point3::new(-z, -x, y),
+
+        16 => Point3::new(x, z, -y),
+        17 => Point3::new(-y, z, -x),
+        18 => Point3::new(-x, z, y),
+        19 => Point3::new(y, z, x),
+
+        20 => Point3::new(x, -z, y),
+        21 => Point3::new(y, -z, -x),
+        22 => Point3::new(-x, -z, -y),
+        23 => Point3::new(-y, -z, x),
+
+        _ => unreachable!(),
+    }
+}
+
+fn in_range(pos1: Point3, pos2: Point3) -> bool {
+    pos1.chebyshev(pos2) <= 1000
+}
+
+fn get_fit(known_positions: &HashSet<Point3>, scanner: &[Point3]) -> Option<(Point3, Rotation)> {
+    let mut best = None;
+    for rotation in 0..24 {
+        let scan_positions: Vec<Point3> = scanner.iter().map(|p| flip(*p, rotation)).collect();
+
+        for known_point in known_positions {
+            cancel::check();
+            for scan_point in &scan_positions {
+                let d = *known_point - *scan_point;
+                assert!(known_positions.contains(&(*scan_point + d)));
+
+                let count = scan_positions
+                    .iter()
+                    .filter(|p| known_positions.contains(&(**p + d)))
+                    .count();
+
+                if let Some((best_count, _, _)) = best {
+                    if count > best_count {
+                        best = Some((count, d, rotation));
+                    }
+                } else {
+                    best = Some((count, d, rotation));
+                }
+            }
+        }
+    }
+
+    best.filter(|(c, _, _)| *c >= 12.min(known_positions.len()))
+        .map(|(_, d, r)| (d, r))
+}
+
+fn run_round(known: usize, input: &[Vec<Point3>], day_2: bool) -> Option<usize> {
+    let mut known_positions: HashSet<Point3> = input[known].iter().copied().collect();
+    let mut remaining_scanners: HashSet<usize> = (0..input.len()).collect();
+    remaining_scanners.remove(&known);
+    let mut known_satellites = HashSet::new();
+    known_satellites.insert(Point3::ZERO);
+
+    while !remaining_scanners.is_empty() {
+        let mut next_scanners = HashSet::new();
+        for scanner_num in &remaining_scanners {
+            let scanner = &input[*scanner_num];
+            if let Some((d, r)) = get_fit(&known_positions, scanner) {
+                known_satellites.insert(d);
+                known_positions.extend(
+                    scanner
+                        .iter()
+                        .map(|p| flip(*p, r) + d)
+                        .collect::<HashSet<Point3>>(),
+                );
+                assert!(known_positions
+                    .iter()
+                    .all(|p| known_satellites.iter().any(|s| in_range(*s, *p))));
+            } else {
+                next_scanners.insert(*scanner_num);
+            }
+        }
+        if remaining_scanners.len() == next_scanners.len() {
+            return None;
+        }
+        remaining_scanners = next_scanners;
+    }
+
+    if !day_2 {
+        Some(known_positions.len())
+    } else {
+        known_satellites
+            .iter()
+            .copied()
+            .flat_map(|p| known_satellites.iter().copied().map(move |p2| (p, p2)))
+            .map(|(p1, p2)| p1.manhattan(p2) as usize)
+            .max()
+    }
+}
+
+pub fn nineteen_impl(input: &[Vec<Point3>], day_2: bool) -> usize {
+    run_round(0, input, day_2).unwrap()
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let sections = sections(input);
+    if sections.is_empty() {
+        return Err(ParseError::missing(input, "a scanner"));
+    }
+    sections
+        .iter()
+        .map(|section| {
+            let header = section[0];
+            if !header.text.starts_with("--- scanner") {
+                return Err(header.error(header.text, "a `--- scanner N ---` header"));
+            }
+            section[1..]
+                .iter()
+                .map(|line| match line.ints(',')?[..] {
+                    [x, y, z] => Ok(Point3::new(x, y, z)),
+                    _ => Err(line.error(line.text, "x,y,z")),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Nineteen;
+
+impl Solution for Nineteen {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        nineteen_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        nineteen_impl(input, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point3;
+    use crate::y2021::day_19::{in_range, parse};
+
+    #[test]
+    fn range() {
+        let scanner = Point3::new(500, 0, -500);
+        assert!(in_range(scanner, Point3::new(-500, 1000, -1500)));
+        assert!(!in_range(scanner, Point3::new(1501, 0, -500)));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(1, parse::<&str>(&[]).unwrap_err().line);
+        assert_eq!(3, parse(&["", ""]).unwrap_err().line);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::answer::Answer;
+use crate::parsing::{lines, Line, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub enum Command {
+    Up(i32),
+    Down(i32),
+    Forward(i32),
+}
+
+impl Command {
+    fn parse(line: Line) -> Result<Command, ParseError> {
+        let mut words = line.text.split(' ');
+        let verb = line.field(words.next(), "forward, up or down")?;
+        let count = line.num(line.field(words.next(), "a count")?)?;
+        match verb {
+            "forward" => Ok(Command::Forward(count)),
+            "up" => Ok(Command::Up(count)),
+            "down" => Ok(Command::Down(count)),
+            _ => Err(line.error(verb, "forward, up or down")),
+        }
+    }
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<Command>, ParseError> {
+    lines(input).map(Command::parse).collect()
+}
+
+pub fn two_1_impl(commands: &[Command]) -> i32 {
+    let mut depth = 0;
+    let mut position = 0;
+
+    for command in commands {
+        match command {
+            Command::Up(d) => depth -= d,
+            Command::Down(d) => depth += d,
+            Command::Forward(d) => position += d,
+        }
+    }
+
+    depth * position
+}
+
+pub fn two_2_impl(commands: &[Command]) -> i32 {
+    let mut depth = 0;
+    let mut position = 0;
+    let mut aim = 0;
+
+    for command in commands {
+        match command {
+            Command::Up(d) => aim -= d,
+            Command::Down(d) => aim += d,
+            Command::Forward(d) => {
+                position += d;
+                depth += d * aim;
+            }
+        }
+    }
+
+    depth * position
+}
+
+pub struct Two;
+
+impl Solution for Two {
+    type Parsed = Vec<Command>;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<Command>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Command>) -> Answer {
+        two_1_impl(input).into()
+    }
+
+    fn part2(input: &Vec<Command>) -> Answer {
+        two_2_impl(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day_2::parse;
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(&["forward 5", "sideways 2"]).unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+        assert_eq!("forward, up or down", err.expected);
+        let err = parse(&["up"]).unwrap_err();
+        assert_eq!(
+            (1, 3, "a count"),
+            (err.line, err.column, err.expected.as_str())
+        );
+    }
+}
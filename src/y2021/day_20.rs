@@ -0,0 +1,79 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parsing::{section, sections, ParseError};
+use crate::solution::Solution;
+
+pub type Parsed = (Vec<bool>, Grid<bool>);
+
+fn to_num<I: IntoIterator<Item = bool>>(bits: I) -> usize {
+    let mut res = 0;
+    for bit in bits {
+        res = (res << 1) | (bit as usize);
+    }
+    res
+}
+
+fn run_round(algo: &[bool], img: &Grid<bool>, def: bool) -> Grid<bool> {
+    Grid::new_with(img.width() + 2, img.height() + 2, |x, y| {
+        algo[to_num(
+            (-1..=1)
+                .flat_map(|y| (-1..=1).map(move |x| (x, y)))
+                .map(|(xx, yy)| {
+                    let x = x as i32;
+                    let y = y as i32;
+                    if img.legal(x + xx - 1, y + yy - 1) {
+                        *img.get((x + xx - 1) as usize, (y + yy - 1) as usize)
+                            .unwrap_or(&def)
+                    } else {
+                        def
+                    }
+                }),
+        )]
+    })
+}
+
+pub fn twenty_impl(input: &Parsed, day_2: bool) -> u32 {
+    let rounds = if !day_2 { 2 } else { 50 };
+    let mut grid = run_round(&input.0, &input.1, false);
+    for i in 1..rounds {
+        grid = run_round(&input.0, &grid, input.0[0] && (i % 2) != 0);
+    }
+    grid.iter().map(|c| *c as u32).sum()
+}
+
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let sections = sections(input);
+    let algo =
+        section(input, &sections, 0, "the enhancement algorithm")?[0].chars("# or .", pixel)?;
+
+    let image = section(input, &sections, 1, "the image")?;
+    let grid = Grid::parse_chars(image.iter().copied(), "# or .", pixel)?;
+
+    Ok((algo, grid))
+}
+
+pub struct Twenty;
+
+impl Solution for Twenty {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        twenty_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        twenty_impl(input, true).into()
+    }
+}
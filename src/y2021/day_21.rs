@@ -1,9 +1,9 @@
+use crate::answer::Answer;
+use crate::parsing::{Line, ParseError};
+use crate::solution::Solution;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-type Parsed = (usize, usize);
+pub type Parsed = (usize, usize);
 
 type GameState = (usize, usize, usize, usize, bool, u8);
 
@@ -68,7 +68,7 @@ fn quantum_dirac(
     wins
 }
 
-fn twentyone_impl(input: &Parsed, day_2: bool) -> usize {
+pub fn twentyone_impl(input: &Parsed, day_2: bool) -> usize {
     if !day_2 {
         let mut state = (input.0, 0, input.1, 0, true, 2);
         let mut n = 0;
@@ -85,50 +85,29 @@ fn twentyone_impl(input: &Parsed, day_2: bool) -> usize {
     p1_wins.max(p2_wins)
 }
 
-fn parse<S: AsRef<str>>(input: &[S]) -> Parsed {
-    let plrs: Vec<usize> = input
-        .iter()
-        .map(|t| t.as_ref().split(' ').last().unwrap().parse().unwrap())
-        .collect();
-    (plrs[0], plrs[1])
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let position = |index| {
+        let line = Line::at(input, index, "a starting position")?;
+        let last = line.text.rsplit(' ').next().unwrap_or(line.text);
+        line.num(last)
+    };
+    Ok((position(0)?, position(1)?))
 }
 
-pub fn twentyone() -> Result<(), std::io::Error> {
-    let file = File::open("21_input")?;
-    let reader = BufReader::new(file);
-    let lines = parse(&reader.lines().map(|s| s.unwrap()).collect::<Vec<_>>());
-    let res = twentyone_impl(&lines, false);
-    println!("Day 21 part 1: {}", res);
-    let res_2 = twentyone_impl(&lines, true);
-    println!("Day 21 part 2: {}", res_2);
-    Ok(())
-}
+pub struct TwentyOne;
+
+impl Solution for TwentyOne {
+    type Parsed = Parsed;
 
-#[cfg(test)]
-mod tests {
-    use crate::day_21::{parse, twentyone_impl};
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        twentyone_impl(input, false).into()
+    }
 
-    #[test]
-    fn it_works() {
-        assert_eq!(
-            739785,
-            twentyone_impl(
-                &parse(&vec![
-                    "Player 1 starting position: 4",
-                    "Player 2 starting position: 8",
-                ]),
-                false
-            )
-        );
-        assert_eq!(
-            444356092776315,
-            twentyone_impl(
-                &parse(&vec![
-                    "Player 1 starting position: 4",
-                    "Player 2 starting position: 8",
-                ]),
-                true
-            )
-        );
+    fn part2(input: &Parsed) -> Answer {
+        twentyone_impl(input, true).into()
     }
 }
@@ -0,0 +1,187 @@
+use crate::answer::Answer;
+use crate::parsing::{lines, ParseError};
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+pub type Parsed = Vec<Cuboid>;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Cuboid {
+    on: bool,
+    x: (isize, isize),
+    y: (isize, isize),
+    z: (isize, isize),
+}
+
+impl Cuboid {
+    fn create_extras(&self, newer: &Cuboid) -> Vec<Cuboid> {
+        let mut res = vec![*self];
+
+        let cube = Cuboid {
+            on: !self.on,
+            x: (self.x.0.max(newer.x.0), self.x.1.min(newer.x.1)),
+            y: (self.y.0.max(newer.y.0), self.y.1.min(newer.y.1)),
+            z: (self.z.0.max(newer.z.0), self.z.1.min(newer.z.1)),
+        };
+
+        if cube.x.0 <= cube.x.1 && cube.y.0 <= cube.y.1 && cube.z.0 <= cube.z.1 {
+            res.push(cube);
+        }
+
+        res
+    }
+
+    fn value(&self) -> isize {
+        let base = match self.on {
+            true => 1,
+            false => -1,
+        };
+        base * (self.x.1 - self.x.0 + 1).abs()
+            * (self.y.1 - self.y.0 + 1).abs()
+            * (self.z.1 - self.z.0 + 1).abs()
+    }
+}
+
+pub fn twentytwo_impl(input: &[Cuboid], day_2: bool) -> usize {
+    if day_2 {
+        let mut cubes: Vec<Cuboid> = vec![];
+
+        for cube in input.iter().copied() {
+            let mut next_cubes = Vec::new();
+            for existing in &mut cubes {
+                next_cubes.extend(existing.create_extras(&cube));
+            }
+            if cube.on {
+                next_cubes.push(cube);
+            }
+            cubes = next_cubes;
+        }
+
+        return cubes
+            .iter()
+            .map(|c| c.value())
+            .sum::<isize>()
+            .try_into()
+            .unwrap();
+    }
+    let mut cubes: HashMap<(isize, isize, isize), bool> = (-50..=50)
+        .flat_map(|z| (-50..=50).flat_map(move |y| (-50..=50).map(move |x| ((x, y, z), false))))
+        .collect();
+
+    for Cuboid {
+        on,
+        x: (minx, maxx),
+        y: (miny, maxy),
+        z: (minz, maxz),
+    } in input.iter().copied()
+    {
+        for x in minx.max(-50)..=maxx.min(50) {
+            for y in miny.max(-50)..=maxy.min(50) {
+                for z in minz.max(-50)..=maxz.min(50) {
+                    cubes.insert((x, y, z), on);
+                }
+            }
+        }
+    }
+
+    cubes.values().filter(|v| **v).count()
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (state, ranges) = line
+                .text
+                .split_once(' ')
+                .ok_or_else(|| line.error_at_end("ranges"))?;
+            let on = match state {
+                "on" => true,
+                "off" => false,
+                _ => return Err(line.error(state, "on or off")),
+            };
+            let mut ranges = ranges.split(',');
+            let mut range = |name| line.range(line.field(ranges.next(), "a range")?, name);
+            Ok(Cuboid {
+                on,
+                x: range("x")?,
+                y: range("y")?,
+                z: range("z")?,
+            })
+        })
+        .collect()
+}
+
+pub struct TwentyTwo;
+
+impl Solution for TwentyTwo {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        twentytwo_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        twentytwo_impl(input, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day_22::{parse, twentytwo_impl};
+
+    #[test]
+    fn overlaps() {
+        assert_eq!(
+            27 - 8,
+            twentytwo_impl(
+                &parse(&["on x=0..2,y=0..2,z=0..2", "off x=1..3,y=1..3,z=1..3"]).unwrap(),
+                true
+            )
+        );
+        assert_eq!(
+            8 - 4,
+            twentytwo_impl(
+                &parse(&["on x=0..1,y=0..1,z=0..1", "off x=1..2,y=0..1,z=0..1"]).unwrap(),
+                true
+            )
+        );
+        assert_eq!(
+            8 - 4,
+            twentytwo_impl(
+                &parse(&["on x=0..1,y=0..1,z=0..1", "off x=-1..0,y=0..1,z=0..1"]).unwrap(),
+                true
+            )
+        );
+        assert_eq!(
+            8 - 4,
+            twentytwo_impl(
+                &parse(&["on x=0..1,y=0..1,z=0..1", "off x=0..1,y=-1..0,z=0..1"]).unwrap(),
+                true
+            )
+        );
+        assert_eq!(
+            8 - 4,
+            twentytwo_impl(
+                &parse(&["on x=0..1,y=0..1,z=0..1", "off x=0..1,y=1..2,z=0..1"]).unwrap(),
+                true
+            )
+        );
+        assert_eq!(
+            27,
+            twentytwo_impl(
+                &parse(&[
+                    "on x=0..2,y=0..2,z=0..2",
+                    "off x=0..2,y=0..2,z=0..2",
+                    "on x=0..2,y=0..2,z=0..2"
+                ])
+                .unwrap(),
+                true
+            )
+        );
+    }
+}
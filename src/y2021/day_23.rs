@@ -1,34 +1,36 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::parsing::{lines, ParseError};
+use crate::point::Point2;
+use crate::solution::Solution;
 use std::collections::{BinaryHeap, HashSet};
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum AmphipodState {
+pub enum AmphipodState {
     Start,
     Middle,
     End,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Amphipod {
-    pos: Point,
+pub struct Amphipod {
+    pos: Point2,
     state: AmphipodState,
 }
 
 impl Amphipod {
-    fn new(pos: Point) -> Amphipod {
+    fn new(pos: Point2) -> Amphipod {
         Amphipod {
             pos,
             state: AmphipodState::Start,
         }
     }
 
-    fn move_to(&mut self, to: Point) {
+    fn move_to(&mut self, to: Point2) {
         self.pos = to;
 
         self.state = match self.state {
-            AmphipodState::Start if to.1 != 1 => AmphipodState::End,
+            AmphipodState::Start if to.y != 1 => AmphipodState::End,
             AmphipodState::Start => AmphipodState::Middle,
             AmphipodState::Middle => AmphipodState::End,
             AmphipodState::End => panic!(),
@@ -41,8 +43,8 @@ impl std::fmt::Display for Amphipod {
         write!(
             f,
             "({}, {}) ({})",
-            self.pos.0,
-            self.pos.1,
+            self.pos.x,
+            self.pos.y,
             match self.state {
                 AmphipodState::Start => "S",
                 AmphipodState::Middle => "M",
@@ -53,13 +55,13 @@ impl std::fmt::Display for Amphipod {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum AmphipodPair {
+pub enum AmphipodPair {
     Part1([Amphipod; 2]),
     Part2([Amphipod; 4]),
 }
 
 impl AmphipodPair {
-    fn is_at(&self, p: Point) -> bool {
+    fn is_at(&self, p: Point2) -> bool {
         match self {
             AmphipodPair::Part1([n1, n2]) => n1.pos == p || n2.pos == p,
             AmphipodPair::Part2([n1, n2, n3, n4]) => {
@@ -68,7 +70,7 @@ impl AmphipodPair {
         }
     }
 
-    fn move_to(&mut self, from: Point, to: Point) {
+    fn move_to(&mut self, from: Point2, to: Point2) {
         assert!(self.is_at(from));
         match self {
             AmphipodPair::Part1([n1, n2]) => {
@@ -101,13 +103,13 @@ impl AmphipodPair {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct State {
+pub struct State {
     pods: [AmphipodPair; 4],
     part2: bool,
 }
 
 impl State {
-    fn _is_accessible(&self, from: Point, to: Point, len: usize) -> Option<usize> {
+    fn _is_accessible(&self, from: Point2, to: Point2, len: usize) -> Option<usize> {
         if len > 0 {
             for pod in &self.pods {
                 if pod.is_at(from) {
@@ -120,35 +122,31 @@ impl State {
             return Some(len);
         }
 
-        if from.0 != to.0 {
-            if from.1 != 1 {
-                self._is_accessible((from.0, from.1 - 1), to, len + 1)
-            } else if from.0 < to.0 {
-                self._is_accessible((from.0 + 1, from.1), to, len + 1)
-            } else {
-                self._is_accessible((from.0 - 1, from.1), to, len + 1)
-            }
-        } else if from.1 < to.1 {
-            self._is_accessible((from.0, from.1 + 1), to, len + 1)
+        let step = if from.x == to.x {
+            Point2::new(0, (to.y - from.y).signum())
+        } else if from.y != 1 {
+            Point2::new(0, -1)
         } else {
-            self._is_accessible((from.0, from.1 - 1), to, len + 1)
-        }
+            Point2::new((to.x - from.x).signum(), 0)
+        };
+        self._is_accessible(from + step, to, len + 1)
     }
 
-    fn is_accessible(&self, from: Point, to: Point) -> Option<usize> {
+    fn is_accessible(&self, from: Point2, to: Point2) -> Option<usize> {
         self._is_accessible(from, to, 0)
     }
 
-    fn get_final_destination_list(&self, i: usize) -> Vec<Point> {
+    fn get_final_destination_list(&self, i: usize) -> Vec<Point2> {
+        let x = room_x(i);
         if self.part2 {
             vec![
-                (2 * i + 3, 5),
-                (2 * i + 3, 4),
-                (2 * i + 3, 3),
-                (2 * i + 3, 2),
+                Point2::new(x, 5),
+                Point2::new(x, 4),
+                Point2::new(x, 3),
+                Point2::new(x, 2),
             ]
         } else {
-            vec![(2 * i + 3, 3), (2 * i + 3, 2)]
+            vec![Point2::new(x, 3), Point2::new(x, 2)]
         }
     }
 
@@ -163,7 +161,7 @@ impl State {
         true
     }
 
-    fn get_lowest_empty_target(&self, i: usize) -> Point {
+    fn get_lowest_empty_target(&self, i: usize) -> Point2 {
         for target in self.get_final_destination_list(i) {
             if !self.pods[i].is_at(target) {
                 return target;
@@ -173,7 +171,7 @@ impl State {
         unreachable!()
     }
 
-    fn get_targets(&self) -> Vec<(usize, Point, Point)> {
+    fn get_targets(&self) -> Vec<(usize, Point2, Point2)> {
         self.pods
             .iter()
             .enumerate()
@@ -183,13 +181,13 @@ impl State {
                     .flat_map(move |pod| {
                         let mut res = Vec::new();
                         if pod.state == AmphipodState::Start {
-                            res.push((1, 1));
-                            res.push((2, 1));
-                            res.push((4, 1));
-                            res.push((6, 1));
-                            res.push((8, 1));
-                            res.push((10, 1));
-                            res.push((11, 1));
+                            res.push(Point2::new(1, 1));
+                            res.push(Point2::new(2, 1));
+                            res.push(Point2::new(4, 1));
+                            res.push(Point2::new(6, 1));
+                            res.push(Point2::new(8, 1));
+                            res.push(Point2::new(10, 1));
+                            res.push(Point2::new(11, 1));
                         };
                         if pod.state != AmphipodState::End {
                             res.push(self.get_lowest_empty_target(i));
@@ -202,7 +200,7 @@ impl State {
             .collect()
     }
 
-    fn state_change(mut self, from: Point, to: Point) -> State {
+    fn state_change(mut self, from: Point2, to: Point2) -> State {
         //println!("{}", self);
         //println!("{:?} -> {:?}", from, to);
         for pod in self.pods {
@@ -218,11 +216,11 @@ impl State {
     }
 
     fn day_2(&mut self) {
-        let mut realpods = vec![vec![], vec![], vec![], vec![]];
+        let mut realpods = [vec![], vec![], vec![], vec![]];
         for (i, pod) in &mut self.pods.iter().enumerate() {
             for mut realpod in pod.get_pods().to_vec() {
-                if realpod.pos.1 == 3 {
-                    realpod.pos = (realpod.pos.0, 5);
+                if realpod.pos.y == 3 {
+                    realpod.pos.y = 5;
                 }
                 realpods[i].push(realpod);
             }
@@ -231,26 +229,26 @@ impl State {
         self.pods = [
             AmphipodPair::Part2([
                 realpods[0][0],
-                Amphipod::new((9, 3)),
-                Amphipod::new((7, 4)),
+                Amphipod::new(Point2::new(9, 3)),
+                Amphipod::new(Point2::new(7, 4)),
                 realpods[0][1],
             ]),
             AmphipodPair::Part2([
                 realpods[1][0],
-                Amphipod::new((7, 3)),
-                Amphipod::new((5, 4)),
+                Amphipod::new(Point2::new(7, 3)),
+                Amphipod::new(Point2::new(5, 4)),
                 realpods[1][1],
             ]),
             AmphipodPair::Part2([
                 realpods[2][0],
-                Amphipod::new((5, 3)),
-                Amphipod::new((9, 4)),
+                Amphipod::new(Point2::new(5, 3)),
+                Amphipod::new(Point2::new(9, 4)),
                 realpods[2][1],
             ]),
             AmphipodPair::Part2([
                 realpods[3][0],
-                Amphipod::new((3, 3)),
-                Amphipod::new((3, 4)),
+                Amphipod::new(Point2::new(3, 3)),
+                Amphipod::new(Point2::new(3, 4)),
                 realpods[3][1],
             ]),
         ];
@@ -264,13 +262,9 @@ impl State {
             .iter()
             .enumerate()
             .flat_map(|(i, pod)| {
-                pod.get_pods().iter().map(move |p| {
-                    if p.pos.0 != 2 * i + 3 {
-                        (((p.pos.0 as i32) - (2 * (i as i32) + 3)).abs()) as usize
-                    } else {
-                        0
-                    }
-                })
+                pod.get_pods()
+                    .iter()
+                    .map(move |p| (p.pos.x - room_x(i)).unsigned_abs() as usize)
             })
             .sum::<usize>()
     }
@@ -294,8 +288,12 @@ impl std::fmt::Display for State {
     }
 }
 
-type Point = (usize, usize);
-type Parsed = State;
+pub type Parsed = State;
+
+/// The column of the room that amphipods of type `ty` belong in.
+fn room_x(ty: usize) -> i32 {
+    2 * ty as i32 + 3
+}
 
 fn calc_cost(ty: usize, len: usize) -> usize {
     match ty {
@@ -314,6 +312,7 @@ fn find_solution_cost(state: &State) -> Option<usize> {
     q.push((usize::MAX, usize::MAX, *state));
 
     while let Some((_, cost, s)) = q.pop() {
+        cancel::check();
         if s.ready() {
             return Some(usize::MAX - cost);
         }
@@ -335,7 +334,7 @@ fn find_solution_cost(state: &State) -> Option<usize> {
     unreachable!()
 }
 
-fn twentythree_impl(input: &Parsed, day_2: bool) -> usize {
+pub fn twentythree_impl(input: &Parsed, day_2: bool) -> usize {
     let mut state = *input;
     if day_2 {
         state.day_2();
@@ -343,28 +342,37 @@ fn twentythree_impl(input: &Parsed, day_2: bool) -> usize {
     find_solution_cost(&state).unwrap()
 }
 
-fn parse<S: AsRef<str>>(input: &[S]) -> Parsed {
-    let mut creatures = vec![vec![], vec![], vec![], vec![]];
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let mut creatures = [vec![], vec![], vec![], vec![]];
 
-    for (y, s) in input.iter().enumerate() {
-        for (x, c) in s.as_ref().chars().enumerate() {
+    for line in lines(input) {
+        let y = line.index;
+        for (i, c) in line.text.char_indices() {
+            let x = line.text[..i].chars().count();
             match c {
                 'A' | 'B' | 'C' | 'D' => {
                     let ty = ((c as u8) - b'A') as usize;
-                    let pos = (x, y);
-                    let state = if (x, y) == (2 * ty + 3, 3) {
+                    if creatures[ty].len() == 2 {
+                        return Err(line.error_at_offset(i, "two amphipods of each type"));
+                    }
+                    let pos = Point2::from((x, y));
+                    let state = if pos == Point2::new(room_x(ty), 3) {
                         AmphipodState::End
                     } else {
                         AmphipodState::Start
                     };
                     creatures[ty].push(Amphipod { pos, state });
                 }
-                _ => {}
+                '#' | '.' | ' ' => {}
+                _ => return Err(line.error_at_offset(i, "a wall, space or amphipod")),
             }
         }
     }
+    if creatures.iter().any(|c| c.len() != 2) {
+        return Err(ParseError::missing(input, "two amphipods of each type"));
+    }
 
-    State {
+    Ok(State {
         pods: [
             AmphipodPair::Part1([creatures[0][0], creatures[0][1]]),
             AmphipodPair::Part1([creatures[1][0], creatures[1][1]]),
@@ -372,51 +380,23 @@ fn parse<S: AsRef<str>>(input: &[S]) -> Parsed {
             AmphipodPair::Part1([creatures[3][0], creatures[3][1]]),
         ],
         part2: false,
-    }
+    })
 }
 
-pub fn twentythree() -> Result<(), std::io::Error> {
-    let file = File::open("23_input")?;
-    let reader = BufReader::new(file);
-    let lines = parse(&reader.lines().map(|s| s.unwrap()).collect::<Vec<_>>());
-    let res = twentythree_impl(&lines, false);
-    println!("Day 23 part 1: {}", res);
-    let res_2 = twentythree_impl(&lines, true);
-    println!("Day 23 part 2: {}", res_2);
-    Ok(())
-}
+pub struct TwentyThree;
+
+impl Solution for TwentyThree {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        twentythree_impl(input, false).into()
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::day_23::{parse, twentythree_impl};
-
-    #[test]
-    fn it_works() {
-        assert_eq!(
-            12521,
-            twentythree_impl(
-                &parse(&vec![
-                    "#############",
-                    "#...........#",
-                    "###B#C#B#D###",
-                    "  #A#D#C#A#  ",
-                    "  #########  ",
-                ]),
-                false
-            )
-        );
-        assert_eq!(
-            44169,
-            twentythree_impl(
-                &parse(&vec![
-                    "#############",
-                    "#...........#",
-                    "###B#C#B#D###",
-                    "  #A#D#C#A#  ",
-                    "  #########  ",
-                ]),
-                true
-            )
-        );
+    fn part2(input: &Parsed) -> Answer {
+        twentythree_impl(input, true).into()
     }
 }
@@ -1,8 +1,8 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::parsing::{lines, Line, ParseError};
+use crate::solution::Solution;
 use rayon::prelude::*;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
-use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Op {
@@ -25,17 +25,11 @@ impl Op {
     }
 
     fn is_zero(&self) -> bool {
-        match self {
-            Op::Num(0) => true,
-            _ => false,
-        }
+        matches!(self, Op::Num(0))
     }
 
     fn is_one(&self) -> bool {
-        match self {
-            Op::Num(1) => true,
-            _ => false,
-        }
+        matches!(self, Op::Num(1))
     }
 
     fn can_be_neg(&self) -> bool {
@@ -69,13 +63,9 @@ impl Op {
     fn is_mul(&self, d: i64) -> bool {
         match self {
             Op::Num(n) => n % d == 0,
-            Op::Add(left, right) => {
-                left.is_mul(d) && right.is_mul(d)
-            }
-            Op::Mul(left, right) => {
-                left.is_mul(d) || right.is_mul(d)
-            }
-            _ => false
+            Op::Add(left, right) => left.is_mul(d) && right.is_mul(d),
+            Op::Mul(left, right) => left.is_mul(d) || right.is_mul(d),
+            _ => false,
         }
     }
 
@@ -106,11 +96,13 @@ impl Op {
             Op::Num(n) => *n,
             Op::Inp(_) => 9,
             Op::Add(left, right) => left.max_value() + right.max_value(),
-            Op::Mul(left, right) => if left.can_be_neg() || right.can_be_neg() {
-                i64::MAX
-            } else {
-                left.max_value() * right.max_value()
-            },
+            Op::Mul(left, right) => {
+                if left.can_be_neg() || right.can_be_neg() {
+                    i64::MAX
+                } else {
+                    left.max_value() * right.max_value()
+                }
+            }
             Op::Div(left, _) => left.max_value(),
             Op::Mod(_, right) => right.max_value(),
             Op::Eql(_, _) => 1,
@@ -122,21 +114,27 @@ impl Op {
             Op::Num(n) => *n,
             Op::Inp(_) => 0,
             Op::Add(left, right) => left.min_value() + right.min_value(),
-            Op::Mul(left, right) => if left.can_be_neg() || right.can_be_neg() {
-                i64::MIN
-            } else {
-                left.min_value() * right.min_value()
-            },
-            Op::Div(left, right) => if left.can_be_neg() || right.can_be_neg() {
-                i64::MIN
-            } else {
-                left.min_value() / right.max_value()
-            },
-            Op::Mod(left, right) => if left.min_value() < right.min_value() && left.max_value() < right.min_value() {
-                left.min_value()
-            } else {
-                0
-            },
+            Op::Mul(left, right) => {
+                if left.can_be_neg() || right.can_be_neg() {
+                    i64::MIN
+                } else {
+                    left.min_value() * right.min_value()
+                }
+            }
+            Op::Div(left, right) => {
+                if left.can_be_neg() || right.can_be_neg() {
+                    i64::MIN
+                } else {
+                    left.min_value() / right.max_value()
+                }
+            }
+            Op::Mod(left, right) => {
+                if left.min_value() < right.min_value() && left.max_value() < right.min_value() {
+                    left.min_value()
+                } else {
+                    0
+                }
+            }
             Op::Eql(_, _) => 0,
         }
     }
@@ -164,22 +162,27 @@ impl Op {
                 Box::new(Op::new(m)),
             ),
             Op::Div(_, _) => Op::Mod(Box::new(self), Box::new(Op::new(m))),
-            Op::Mod(left, right) => if right.is_num() && right.get_num() == m {
-                Op::Mod(left, right)
-            } else {
-                Op::Mod(Box::new(Op::Mod(left, right)), Box::new(Op::new(m)))
-            },
+            Op::Mod(left, right) => {
+                if right.is_num() && right.get_num() == m {
+                    Op::Mod(left, right)
+                } else {
+                    Op::Mod(Box::new(Op::Mod(left, right)), Box::new(Op::new(m)))
+                }
+            }
             Op::Eql(_, _) => self,
         }
     }
 
     fn do_div(self, d: i64) -> Op {
         match self {
-            Op::Num(n) => { assert!(n%d == 0); Op::Num(n/d) },
+            Op::Num(n) => {
+                assert!(n % d == 0);
+                Op::Num(n / d)
+            }
             Op::Add(left, right) => Op::Add(Box::new(left.do_div(d)), Box::new(right.do_div(d))),
             Op::Mul(left, right) if left.is_mul(d) => *right,
             Op::Mul(left, right) if right.is_mul(d) => *left,
-            _ => panic!()
+            _ => panic!(),
         }
     }
 
@@ -191,17 +194,11 @@ impl Op {
     }
 
     fn is_num(&self) -> bool {
-        match self {
-            Op::Num(_) => true,
-            _ => false,
-        }
+        matches!(self, Op::Num(_))
     }
 
     fn is_inp(&self) -> bool {
-        match self {
-            Op::Inp(_) => true,
-            _ => false,
-        }
+        matches!(self, Op::Inp(_))
     }
 
     fn reduce(self) -> Box<Op> {
@@ -245,17 +242,25 @@ impl Op {
                 match (*left, *right) {
                     (left, right) if left.is_zero() => Box::new(right),
                     (left, right) if right.is_zero() => Box::new(left),
-                    (left, right) if left.is_num() && right.is_num() =>
-                        Box::new(Op::new(left.get_num() + right.get_num())),
+                    (left, right) if left.is_num() && right.is_num() => {
+                        Box::new(Op::new(left.get_num() + right.get_num()))
+                    }
                     (Op::Add(n, x), m)
                     | (Op::Add(x, n), m)
                     | (m, Op::Add(n, x))
                     | (m, Op::Add(x, n))
                         if n.is_num() && m.is_num() =>
-                        Op::Add(Box::new(Op::new(n.get_num() + m.get_num())), x).reduce(),
-                    (Op::Add(n, x), op) | (Op::Add(x, n), op)
-                    | (op, Op::Add(n, x)) | (op, Op::Add(x, n)) if !n.is_inp() && x.is_inp() =>
-                        Op::Add(Box::new(Op::Add(Box::new(op), n)), x).reduce(),
+                    {
+                        Op::Add(Box::new(Op::new(n.get_num() + m.get_num())), x).reduce()
+                    }
+                    (Op::Add(n, x), op)
+                    | (Op::Add(x, n), op)
+                    | (op, Op::Add(n, x))
+                    | (op, Op::Add(x, n))
+                        if !n.is_inp() && x.is_inp() =>
+                    {
+                        Op::Add(Box::new(Op::Add(Box::new(op), n)), x).reduce()
+                    }
                     (left, right) => Box::new(Op::Add(Box::new(left), Box::new(right))),
                 }
             }
@@ -268,18 +273,25 @@ impl Op {
                     | (m, Op::Mul(n, x))
                     | (m, Op::Mul(x, n))
                         if n.is_num() && m.is_num() =>
-                        Op::Mul(Box::new(Op::new(n.get_num() * m.get_num())), x).reduce(),
-                   (Op::Add(x, n), m) | (m, Op::Add(x, n)) if m.is_num() =>
-                        Box::new(Op::Add(
-                            Op::Mul(n, Box::new(m.clone())).reduce(),
-                            Box::new(Op::Mul(Box::new(m), x)),
-                        )),
-                   (Op::Div(x, n), m) | (m, Op::Div(x, n)) if m.is_num() && n.is_num() && m.get_num() == n.get_num() => x,
-                    (left, right) if left.is_num() && right.is_num() => Box::new(Op::new(left.get_num() * right.get_num())),
+                    {
+                        Op::Mul(Box::new(Op::new(n.get_num() * m.get_num())), x).reduce()
+                    }
+                    (Op::Add(x, n), m) | (m, Op::Add(x, n)) if m.is_num() => Box::new(Op::Add(
+                        Op::Mul(n, Box::new(m.clone())).reduce(),
+                        Box::new(Op::Mul(Box::new(m), x)),
+                    )),
+                    (Op::Div(x, n), m) | (m, Op::Div(x, n))
+                        if m.is_num() && n.is_num() && m.get_num() == n.get_num() =>
+                    {
+                        x
+                    }
+                    (left, right) if left.is_num() && right.is_num() => {
+                        Box::new(Op::new(left.get_num() * right.get_num()))
+                    }
                     (left, right) if left.is_zero() || right.is_zero() => Box::new(Op::new(0)),
-                    (left, right) if left.is_one() => Box::new(right), 
+                    (left, right) if left.is_one() => Box::new(right),
                     (left, right) if right.is_one() => Box::new(left),
-                    (left, right) => Box::new(Op::Mul(Box::new(left), Box::new(right)))
+                    (left, right) => Box::new(Op::Mul(Box::new(left), Box::new(right))),
                 }
             }
             Op::Div(left, right) => {
@@ -293,16 +305,24 @@ impl Op {
                         let (a, b) = (left.get_num(), right.get_num());
                         Box::new(Op::Num(a / b))
                     }
-                    (Op::Add(a, b), m) if m.is_num() =>
-                        Box::new(Op::Add(
-                            Op::Div(a, Box::new(m.clone())).reduce(),
-                            Op::Div(b, Box::new(m.clone())).reduce(),
-                        )),
-                   (Op::Mul(x, n), m) | (Op::Mul(n, x), m) if m.is_num() && n.is_num() && m.get_num() == n.get_num() => x,
-                   (Op::Mul(x, n), m) | (Op::Mul(n, x), m) if m.is_num() && n.is_num() && n.get_num() % m.get_num() == 0 =>
-                       Op::Mul(Box::new(Op::Num(n.get_num() / m.get_num())), x).reduce(),
-                    (left, right) if left.is_inp() && right.min_value() >= 10 => Box::new(Op::new(0)),
-                    (left, right) => Box::new(Op::Div(Box::new(left), Box::new(right)))
+                    (Op::Add(a, b), m) if m.is_num() => Box::new(Op::Add(
+                        Op::Div(a, Box::new(m.clone())).reduce(),
+                        Op::Div(b, Box::new(m.clone())).reduce(),
+                    )),
+                    (Op::Mul(x, n), m) | (Op::Mul(n, x), m)
+                        if m.is_num() && n.is_num() && m.get_num() == n.get_num() =>
+                    {
+                        x
+                    }
+                    (Op::Mul(x, n), m) | (Op::Mul(n, x), m)
+                        if m.is_num() && n.is_num() && n.get_num() % m.get_num() == 0 =>
+                    {
+                        Op::Mul(Box::new(Op::Num(n.get_num() / m.get_num())), x).reduce()
+                    }
+                    (left, right) if left.is_inp() && right.min_value() >= 10 => {
+                        Box::new(Op::new(0))
+                    }
+                    (left, right) => Box::new(Op::Div(Box::new(left), Box::new(right))),
                 }
             }
             Op::Mod(left, right) => {
@@ -310,21 +330,28 @@ impl Op {
                 let right = right.reduce();
                 match (*left, right) {
                     (left, right) if left.max_value() < right.max_value() => Box::new(left),
-                    (Op::Mod(lleft, lright), right) if lright.is_num() && right.is_num() && lright.get_num() == right.get_num()
-                        => Op::Mod(lleft, lright).reduce(),
+                    (Op::Mod(lleft, lright), right)
+                        if lright.is_num()
+                            && right.is_num()
+                            && lright.get_num() == right.get_num() =>
+                    {
+                        Op::Mod(lleft, lright).reduce()
+                    }
                     (left, right) if right.is_num() => Box::new(left.do_mod_div(right.get_num())),
-                    (left, right) => Box::new(Op::Mod(Box::new(left), right))
+                    (left, right) => Box::new(Op::Mod(Box::new(left), right)),
                 }
             }
             Op::Eql(left, right) => {
                 let left = left.reduce();
                 let right = right.reduce();
                 match (*left, *right) {
-                    (left, right) if left.is_num() && right.is_num() => Box::new(Op::new((left.get_num() == right.get_num()) as i64)),
+                    (left, right) if left.is_num() && right.is_num() => {
+                        Box::new(Op::new((left.get_num() == right.get_num()) as i64))
+                    }
                     (left, right) if left.is_zero() && right.is_zero() => Box::new(Op::new(1)),
                     (left, right) if left.min_value() > right.max_value() => Box::new(Op::new(0)),
                     (left, right) if left.max_value() < right.min_value() => Box::new(Op::new(0)),
-                    (left, right) => Box::new(Op::Eql(Box::new(left), Box::new(right)))
+                    (left, right) => Box::new(Op::Eql(Box::new(left), Box::new(right))),
                 }
             }
         }
@@ -332,7 +359,7 @@ impl Op {
 
     fn rewrite(&mut self, terms: [u8; 14]) {
         match self {
-            Op::Num(_) => {},
+            Op::Num(_) => {}
             Op::Inp(i) => *self = Op::Num(terms[*i as usize] as i64),
             Op::Add(left, right)
             | Op::Mul(left, right)
@@ -341,7 +368,7 @@ impl Op {
             | Op::Eql(left, right) => {
                 left.rewrite(terms);
                 right.rewrite(terms);
-            },
+            }
         }
     }
 }
@@ -364,13 +391,13 @@ impl std::fmt::Display for Op {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum NumOrAddr {
+pub enum NumOrAddr {
     Num(i64),
     Addr(u8),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Instr {
+pub enum Instr {
     Inp(u8),
     Add(u8, NumOrAddr),
     Mul(u8, NumOrAddr),
@@ -379,7 +406,7 @@ enum Instr {
     Eql(u8, NumOrAddr),
 }
 
-type Parsed = Vec<Instr>;
+pub type Parsed = Vec<Instr>;
 type Memory = [Op; 4];
 
 struct State {
@@ -411,64 +438,66 @@ impl State {
     fn op_add(&self, addr: u8, val: NumOrAddr) -> Op {
         let mem_val = self.read(addr);
         let op_val = self.read_val(val);
-        let res = *match (mem_val, op_val) {
+
+        *match (mem_val, op_val) {
             (n, op) | (op, n) if n.is_zero() => op,
             (Op::Num(a), Op::Num(b)) => Op::new(a + b),
             (left, right) => Op::Add(Box::new(left), Box::new(right)),
-        }.reduce();
-        res
+        }
+        .reduce()
     }
 
     fn op_mul(&self, addr: u8, val: NumOrAddr) -> Op {
         let mem_val = self.read(addr);
         let op_val = self.read_val(val);
-        let res = match (mem_val, op_val) {
+
+        match (mem_val, op_val) {
             (n, _) | (_, n) if n.is_zero() => Op::Num(0),
             (n, op) | (op, n) if n.is_one() => op,
             (Op::Num(b), op) | (op, Op::Num(b)) if op.is_div(b) => op.do_mul(b),
             (Op::Num(a), Op::Num(b)) => Op::new(a * b),
             (left, right) => Op::Mul(Box::new(left), Box::new(right)),
-        };
-        res
+        }
     }
 
     fn op_div(&self, addr: u8, val: NumOrAddr) -> Op {
         let mem_val = self.read(addr);
         let op_val = self.read_val(val);
-        let res = match (mem_val, op_val) {
+
+        match (mem_val, op_val) {
             (n, _) if n.is_zero() => Op::Num(0),
             (_, n) if n.is_zero() => panic!(),
             (op, n) if n.is_one() => op,
             (op, Op::Num(b)) if op.is_mul(b) => op.do_div(b),
             (Op::Num(a), Op::Num(b)) => Op::new(a / b),
             (left, right) => Op::Div(Box::new(left), Box::new(right)),
-        };
-        res
+        }
     }
 
     fn op_mod(&self, addr: u8, val: NumOrAddr) -> Op {
         let mem_val = self.read(addr);
         let op_val = self.read_val(val);
-        let res = *match (mem_val, op_val) {
+
+        *match (mem_val, op_val) {
             (n, _) if n.is_zero() => Op::Num(0),
             (_, n) if n.is_zero() => panic!(),
             (Op::Inp(a), n) if !n.is_digit() => Op::new_inp(a),
             (Op::Num(a), Op::Num(b)) => Op::new(a % b),
             (op, Op::Num(b)) => op.do_mod_div(b),
             (left, right) => Op::Mod(Box::new(left), Box::new(right)),
-        }.reduce();
-        res
+        }
+        .reduce()
     }
 
     fn op_eql(&self, addr: u8, val: NumOrAddr) -> Op {
         let mem_val = self.read(addr);
         let op_val = self.read_val(val);
-        let res = match (mem_val, op_val) {
+
+        match (mem_val, op_val) {
             (n, Op::Inp(_)) | (Op::Inp(_), n) if n.is_zero() || !n.is_digit() => Op::Num(0),
             (Op::Num(a), Op::Num(b)) => Op::new((a == b) as i64),
             (left, right) => Op::Eql(Box::new(left), Box::new(right)),
-        };
-        res
+        }
     }
 
     fn run_program(mut self, program: &[Instr], mut input: Vec<i64>) -> Memory {
@@ -495,11 +524,12 @@ impl State {
     }
 }
 
-fn twentyfour_impl(input: &Parsed, day_2: bool) -> usize {
-    if day_2 {
-        unimplemented!();
-    }
+/// The largest model number accepted by the program, as its 14 digits
+/// read from left to right.
+fn largest_model_number(input: &Parsed) -> Option<usize> {
     let stmt = State::new().run_program(input, [0; 14].to_vec());
+    // The rayon threads do not share this thread's cancellation token.
+    let token = cancel::current();
 
     for x_13 in (1..=9).rev() {
         for x_12 in (1..=9).rev() {
@@ -507,129 +537,163 @@ fn twentyfour_impl(input: &Parsed, day_2: bool) -> usize {
                 let x_10 = x_11;
                 for x_9 in (1..=9).rev() {
                     for x_8 in (1..=9).rev() {
-                        (2..=9).into_par_iter().rev().for_each(|x_7| {
-                            let x_6 = x_7 - 1;
-                            for x_5 in (1..=9).rev() {
-                                for x_4 in (1..=9).rev() {
-                                    for x_3 in (1..=9).rev() {
-                                        for x_2 in (1..=9).rev() {
-                                            for x_1 in (1..=9).rev() {
-                                                for x_0 in (1..=9).rev() {
-                                                    let mut new_stmt = stmt[3].clone();
-                                                    let params = [
-                                                         x_0, x_1, x_2, x_3,
-                                                         x_4, x_5, x_6, x_7,
-                                                         x_8, x_9, x_10, x_11,
-                                                         x_12, x_13,
-                                                    ];
-                                                    new_stmt.rewrite(params);
-
-                                                    if x_3 == 9 && x_2 == 9 && x_1 == 9 && x_0 == 9 {
-                                                        println!("{:?}", params);
-                                                    }
-                                                    if *new_stmt.reduce() == Op::Num(0) {
-                                                        println!("Found solution: {:?}", params);
-                                                        panic!();
+                        let found = (2..=9).into_par_iter().rev().find_map_first(|x_7| {
+                            cancel::with_token(&token, || {
+                                let x_6 = x_7 - 1;
+                                for x_5 in (1..=9).rev() {
+                                    for x_4 in (1..=9).rev() {
+                                        for x_3 in (1..=9).rev() {
+                                            for x_2 in (1..=9).rev() {
+                                                for x_1 in (1..=9).rev() {
+                                                    for x_0 in (1..=9).rev() {
+                                                        cancel::check();
+                                                        let mut new_stmt = stmt[3].clone();
+                                                        let params = [
+                                                            x_0, x_1, x_2, x_3, x_4, x_5, x_6, x_7,
+                                                            x_8, x_9, x_10, x_11, x_12, x_13,
+                                                        ];
+                                                        new_stmt.rewrite(params);
+
+                                                        if x_3 == 9
+                                                            && x_2 == 9
+                                                            && x_1 == 9
+                                                            && x_0 == 9
+                                                        {
+                                                            trace!("{:?}", params);
+                                                        }
+                                                        if *new_stmt.reduce() == Op::Num(0) {
+                                                            return Some(params);
+                                                        }
                                                     }
                                                 }
                                             }
                                         }
                                     }
                                 }
-                            }
+                                None
+                            })
                         });
+                        if let Some(params) = found {
+                            return Some(params.iter().rev().fold(0, |n, &x| n * 10 + x as usize));
+                        }
                     }
                 }
             }
         }
     }
 
-    unimplemented!();
+    None
 }
 
-fn parse<S: AsRef<str>>(input: &[S]) -> Parsed {
-    fn parse_addr(s: &str) -> u8 {
-        let c = s.chars().next().unwrap();
-        match c {
-            'x' | 'y' | 'z' | 'w' => (c as u8) - b'w',
-            _ => panic!(),
+pub fn twentyfour_impl(input: &Parsed, day_2: bool) -> usize {
+    if day_2 {
+        unimplemented!();
+    }
+    largest_model_number(input).expect("no model number is accepted")
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    fn parse_addr(line: &Line, s: &str) -> Result<u8, ParseError> {
+        match s {
+            "x" | "y" | "z" | "w" => Ok(s.as_bytes()[0] - b'w'),
+            _ => Err(line.error(s, "a register")),
         }
     }
-    fn parse_num_or_addr(s: &str) -> NumOrAddr {
-        let c = s.chars().next().unwrap();
-        match c {
-            'x' | 'y' | 'z' | 'w' => NumOrAddr::Addr((c as u8) - b'w'),
-            _ => NumOrAddr::Num(s.parse().unwrap()),
+    fn parse_num_or_addr(line: &Line, s: &str) -> Result<NumOrAddr, ParseError> {
+        match s {
+            "x" | "y" | "z" | "w" => Ok(NumOrAddr::Addr(s.as_bytes()[0] - b'w')),
+            _ => Ok(NumOrAddr::Num(line.num(s)?)),
         }
     }
 
-    input
-        .iter()
-        .map(|s| {
-            let parts = s.as_ref().split(' ').collect::<Vec<_>>();
-            let addr = parse_addr(parts[1]);
-            if parts[0] == "inp" {
-                return Instr::Inp(addr);
+    lines(input)
+        .map(|line| {
+            let mut parts = line.text.split(' ');
+            let op = line.field(parts.next(), "an instruction")?;
+            let addr = parse_addr(&line, line.field(parts.next(), "a register")?)?;
+            if op == "inp" {
+                return Ok(Instr::Inp(addr));
             }
-            let operand = parse_num_or_addr(parts[2]);
-            match parts[0] {
+            let operand = parse_num_or_addr(&line, line.field(parts.next(), "an operand")?)?;
+            Ok(match op {
                 "add" => Instr::Add(addr, operand),
                 "mul" => Instr::Mul(addr, operand),
                 "div" => Instr::Div(addr, operand),
                 "mod" => Instr::Mod(addr, operand),
                 "eql" => Instr::Eql(addr, operand),
-                op => unimplemented!("{}", op),
-            }
+                _ => return Err(line.error(op, "inp, add, mul, div, mod or eql")),
+            })
         })
         .collect()
 }
 
-pub fn twentyfour() -> Result<(), std::io::Error> {
-    let file = File::open("24_input")?;
-    let reader = BufReader::new(file);
-    let lines = parse(&reader.lines().map(|s| s.unwrap()).collect::<Vec<_>>());
-    let res = twentyfour_impl(&lines, false);
-    println!("Day 24 part 1: {}", res);
-    let res_2 = twentyfour_impl(&lines, true);
-    println!("Day 24 part 2: {}", res_2);
-    Ok(())
+pub struct TwentyFour;
+
+impl Solution for TwentyFour {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        twentyfour_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        twentyfour_impl(input, true).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day_24::{parse, Memory, Parsed, State};
+    use crate::y2021::day_24::{parse, Op, Parsed, State};
 
-    fn run_program(program: &Parsed, input: Vec<i64>) -> Memory {
-        State::new().run_program(program, input)
+    fn run_program(program: &Parsed, input: Vec<i64>) -> [i64; 4] {
+        let mut terms = [0; 14];
+        for (i, n) in input.iter().enumerate() {
+            terms[input.len() - 1 - i] = *n as u8;
+        }
+        let mem = State::new().run_program(program, input);
+        let mut res = [0; 4];
+        for (i, mut op) in mem.iter().cloned().enumerate() {
+            op.rewrite(terms);
+            res[i] = match *op.reduce() {
+                Op::Num(n) => n,
+                op => panic!("{}", op),
+            };
+        }
+        res
     }
 
     #[test]
     fn it_works() {
         assert_eq!(
             [0, -2, 0, 0],
-            run_program(&parse(&vec!["inp x", "mul x -1",]), vec![2],)
+            run_program(&parse(&["inp x", "mul x -1"]).unwrap(), vec![2],)
         );
         assert_eq!(
             [0, 4, 0, 0],
             run_program(
-                &parse(&vec!["inp z", "inp x", "mul z 3", "eql z x",]),
+                &parse(&["inp z", "inp x", "mul z 3", "eql z x"]).unwrap(),
                 vec![2, 4],
             )
         );
         assert_eq!(
             [0, 6, 0, 1],
             run_program(
-                &parse(&vec!["inp z", "inp x", "mul z 3", "eql z x",]),
+                &parse(&["inp z", "inp x", "mul z 3", "eql z x"]).unwrap(),
                 vec![2, 6],
             )
         );
         assert_eq!(
             [0, 1, 0, 1],
             run_program(
-                &parse(&vec![
+                &parse(&[
                     "inp w", "add z w", "mod z 2", "div w 2", "add y w", "mod y 2", "div w 2",
-                    "add x w", "mod x 2", "div w 2", "mod w 2",
-                ]),
+                    "add x w", "mod x 2", "div w 2", "mod w 2"
+                ])
+                .unwrap(),
                 vec![5],
             )
         );
@@ -1,16 +1,17 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::parsing::{lines, Line, ParseError};
+use crate::solution::Solution;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum NumOrAddr {
+pub enum NumOrAddr {
     Num(i32),
     Addr(u8),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Instr {
+pub enum Instr {
     Inp(u8),
     Add(u8, NumOrAddr),
     Mul(u8, NumOrAddr),
@@ -19,7 +20,7 @@ enum Instr {
     Eql(u8, NumOrAddr),
 }
 
-type Parsed = Vec<Instr>;
+pub type Parsed = Vec<Instr>;
 
 fn read(memory: [i32; 4], addr: NumOrAddr) -> i32 {
     match addr {
@@ -40,9 +41,10 @@ fn run_program(
     code: usize,
     memory: [i32; 4],
 ) -> usize {
+    cancel::check();
     if ip == program.len() {
         if code % 10000000000 == 9999999999 {
-            println!("{}", code);
+            trace!("{}", code);
         }
         if memory[3] == 0 {
             return code;
@@ -57,7 +59,7 @@ fn run_program(
     history.insert((ip, memory));
 
     match program[ip] {
-        Instr::Inp(addr) => {
+        Instr::Inp(_addr) => {
             if code == 0 {
                 for n in (1..=9).rev() {
                     let c = run_program(
@@ -141,9 +143,10 @@ fn run_program_2(
     code: usize,
     memory: [i32; 4],
 ) -> usize {
+    cancel::check();
     if ip == program.len() {
         if code % 10000000000 == 1111111111 {
-            println!("{}", code);
+            trace!("{}", code);
         }
         if memory[3] == 0 {
             return code;
@@ -158,7 +161,7 @@ fn run_program_2(
     history.insert((ip, memory));
 
     match program[ip] {
-        Instr::Inp(addr) => {
+        Instr::Inp(_addr) => {
             if code == 0 {
                 for n in 1..=9 {
                     let c = run_program_2(
@@ -235,7 +238,7 @@ fn run_program_2(
     }
 }
 
-fn twentyfour_impl(input: &Parsed, day_2: bool) -> usize {
+pub fn twentyfour_impl(input: &Parsed, day_2: bool) -> usize {
     if day_2 {
         run_program_2(input, &mut HashSet::new(), 0, 0, [0, 0, 0, 0])
     } else {
@@ -243,52 +246,57 @@ fn twentyfour_impl(input: &Parsed, day_2: bool) -> usize {
     }
 }
 
-fn parse<S: AsRef<str>>(input: &[S]) -> Parsed {
-    fn parse_addr(s: &str) -> u8 {
-        let c = s.chars().next().unwrap();
-        match c {
-            'x' | 'y' | 'z' | 'w' => (c as u8) - b'w',
-            _ => panic!(),
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    fn parse_addr(line: &Line, s: &str) -> Result<u8, ParseError> {
+        match s {
+            "x" | "y" | "z" | "w" => Ok(s.as_bytes()[0] - b'w'),
+            _ => Err(line.error(s, "a register")),
         }
     }
-    fn parse_num_or_addr(s: &str) -> NumOrAddr {
-        let c = s.chars().next().unwrap();
-        match c {
-            'x' | 'y' | 'z' | 'w' => NumOrAddr::Addr((c as u8) - b'w'),
-            _ => NumOrAddr::Num(s.parse().unwrap()),
+    fn parse_num_or_addr(line: &Line, s: &str) -> Result<NumOrAddr, ParseError> {
+        match s {
+            "x" | "y" | "z" | "w" => Ok(NumOrAddr::Addr(s.as_bytes()[0] - b'w')),
+            _ => Ok(NumOrAddr::Num(line.num(s)?)),
         }
     }
 
-    input
-        .iter()
-        .map(|s| {
-            let parts = s.as_ref().split(' ').collect::<Vec<_>>();
-            let addr = parse_addr(parts[1]);
-            if parts[0] == "inp" {
-                return Instr::Inp(addr);
+    lines(input)
+        .map(|line| {
+            let mut parts = line.text.split(' ');
+            let op = line.field(parts.next(), "an instruction")?;
+            let addr = parse_addr(&line, line.field(parts.next(), "a register")?)?;
+            if op == "inp" {
+                return Ok(Instr::Inp(addr));
             }
-            let operand = parse_num_or_addr(parts[2]);
-            match parts[0] {
+            let operand = parse_num_or_addr(&line, line.field(parts.next(), "an operand")?)?;
+            Ok(match op {
                 "add" => Instr::Add(addr, operand),
                 "mul" => Instr::Mul(addr, operand),
                 "div" => Instr::Div(addr, operand),
                 "mod" => Instr::Mod(addr, operand),
                 "eql" => Instr::Eql(addr, operand),
-                op => unimplemented!("{}", op),
-            }
+                _ => return Err(line.error(op, "inp, add, mul, div, mod or eql")),
+            })
         })
         .collect()
 }
 
-pub fn twentyfour() -> Result<(), std::io::Error> {
-    let file = File::open("24_input")?;
-    let reader = BufReader::new(file);
-    let lines = parse(&reader.lines().map(|s| s.unwrap()).collect::<Vec<_>>());
-    let res_2 = twentyfour_impl(&lines, true);
-    println!("Day 24 part 2: {}", res_2);
-    let res = twentyfour_impl(&lines, false);
-    println!("Day 24 part 1: {}", res);
-    Ok(())
+pub struct TwentyFour;
+
+impl Solution for TwentyFour {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        twentyfour_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        twentyfour_impl(input, true).into()
+    }
 }
 
 #[cfg(test)]
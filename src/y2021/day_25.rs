@@ -0,0 +1,79 @@
+use crate::answer::Answer;
+use crate::grid::Grid;
+use crate::parsing::{lines, ParseError};
+use crate::point::Point2;
+use crate::solution::Solution;
+use crate::sparse_grid::SparseGrid;
+
+pub type Parsed = (SparseGrid<char>, Point2);
+
+/// Moves every cucumber of `herd` that faces an empty spot one `step` on a
+/// floor of `size` whose edges wrap around. Returns whether any moved.
+fn move_herd(floor: &mut SparseGrid<char>, herd: char, step: Point2, size: Point2) -> bool {
+    let mut next = SparseGrid::new('.');
+    let mut moved = false;
+    for (p, &cucumber) in floor.iter() {
+        let to = (p + step).wrap(size);
+        if cucumber == herd && !floor.contains(to) {
+            next.set(to, cucumber);
+            moved = true;
+        } else {
+            next.set(p, cucumber);
+        }
+    }
+    *floor = next;
+    moved
+}
+
+pub fn twentyfive_impl(input: &Parsed, day_2: bool) -> usize {
+    if day_2 {
+        panic!();
+    }
+
+    let (mut floor, size) = input.clone();
+    let mut count = 0;
+
+    let mut moved = true;
+    while moved {
+        trace!(
+            "{:#}",
+            Grid::new_with(size.x as usize, size.y as usize, |x, y| {
+                *floor.get(Point2::from((x, y)))
+            })
+        );
+
+        moved = move_herd(&mut floor, '>', Point2::new(1, 0), size);
+        moved |= move_herd(&mut floor, 'v', Point2::new(0, 1), size);
+
+        count += 1;
+    }
+
+    count
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let grid = Grid::parse_chars(lines(input), "`.`, `>` or `v`", |c| match c {
+        '.' | '>' | 'v' => Some(c),
+        _ => None,
+    })?;
+    let size = Point2::from((grid.width(), grid.height()));
+    Ok((SparseGrid::from_grid(&grid, '.'), size))
+}
+
+pub struct TwentyFive;
+
+impl Solution for TwentyFive {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        twentyfive_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        twentyfive_impl(input, true).into()
+    }
+}
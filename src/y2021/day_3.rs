@@ -1,8 +1,8 @@
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
+use crate::answer::Answer;
+use crate::parsing::{lines, ParseError};
+use crate::solution::Solution;
 
-fn three_1_impl<T: AsRef<str>>(report: &[T]) -> i32 {
+pub fn three_1_impl<T: AsRef<str>>(report: &[T]) -> i32 {
     let num: Vec<Vec<bool>> = report
         .iter()
         .map(|t| {
@@ -65,9 +65,9 @@ fn gamma(report: &[Vec<bool>]) -> i32 {
             return vec_to_num(&current_sets[0]);
         }
         if is_true_most_common_in_index(&current_sets, i) {
-            current_sets = current_sets.into_iter().filter(|v| v[i]).collect();
+            current_sets.retain(|v| v[i]);
         } else {
-            current_sets = current_sets.into_iter().filter(|v| !v[i]).collect();
+            current_sets.retain(|v| !v[i]);
         }
     }
 
@@ -84,9 +84,9 @@ fn epsilon(report: &[Vec<bool>]) -> i32 {
             return vec_to_num(&current_sets[0]);
         }
         if is_false_most_common_in_index(&current_sets, i) {
-            current_sets = current_sets.into_iter().filter(|v| v[i]).collect();
+            current_sets.retain(|v| v[i]);
         } else {
-            current_sets = current_sets.into_iter().filter(|v| !v[i]).collect();
+            current_sets.retain(|v| !v[i]);
         }
     }
 
@@ -94,7 +94,7 @@ fn epsilon(report: &[Vec<bool>]) -> i32 {
     vec_to_num(&current_sets[0])
 }
 
-fn three_2_impl<T: AsRef<str>>(report: &[T]) -> (i32, i32, i32) {
+pub fn three_2_impl<T: AsRef<str>>(report: &[T]) -> (i32, i32, i32) {
     let num: Vec<Vec<bool>> = report
         .iter()
         .map(|t| {
@@ -113,30 +113,59 @@ fn three_2_impl<T: AsRef<str>>(report: &[T]) -> (i32, i32, i32) {
     (g, e, g * e)
 }
 
-pub fn three() -> Result<(), std::io::Error> {
-    let file = File::open("3_input")?;
-    let reader = BufReader::new(file);
-    let lines: Vec<String> = reader.lines().map(|s| s.unwrap()).collect();
+/// Checks that the report is rows of binary digits of equal width. The
+/// solvers work on the rows as text.
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<String>, ParseError> {
+    let width = match input.first() {
+        Some(row) => row.as_ref().len(),
+        None => return Err(ParseError::missing(input, "a binary number")),
+    };
+    lines(input)
+        .map(|line| {
+            line.chars("0 or 1", |c| c.to_digit(2))?;
+            if line.text.len() != width {
+                return Err(
+                    line.error_at_offset(width.min(line.text.len()), &format!("{} digits", width))
+                );
+            }
+            Ok(line.text.to_string())
+        })
+        .collect()
+}
+
+pub struct Three;
 
-    let res = three_1_impl(&lines);
-    println!("Day 3 part 1: {}", res);
-    let res2 = three_2_impl(&lines);
-    println!("Day 3 part 2: {:?}", res2);
-    Ok(())
+impl Solution for Three {
+    type Parsed = Vec<String>;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<String>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<String>) -> Answer {
+        three_1_impl(input).into()
+    }
+
+    fn part2(input: &Vec<String>) -> Answer {
+        three_2_impl(input).2.into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day_3::{three_1_impl, three_2_impl};
+    use crate::y2021::day_3::parse;
 
     #[test]
-    fn it_works() {
-        let lines = vec![
-            "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000",
-            "11001", "00010", "01010",
-        ];
-
-        assert_eq!(198, three_1_impl(&lines));
-        assert_eq!((23, 10, 230), three_2_impl(&lines));
+    fn parse_errors() {
+        let err = parse(&["0101", "0121"]).unwrap_err();
+        assert_eq!(
+            (2, 3, "0 or 1"),
+            (err.line, err.column, err.expected.as_str())
+        );
+        let err = parse(&["0101", "01"]).unwrap_err();
+        assert_eq!(
+            (2, 3, "4 digits"),
+            (err.line, err.column, err.expected.as_str())
+        );
     }
 }
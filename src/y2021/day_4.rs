@@ -0,0 +1,143 @@
+use crate::answer::Answer;
+use crate::parsing::{section, sections, Line, ParseError};
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+fn get_victory_row(board: &[Vec<usize>], draws: &HashSet<usize>) -> Option<usize> {
+    for row in board {
+        if row.iter().all(|i| draws.contains(i)) {
+            return Some(
+                board[0..5]
+                    .iter()
+                    .flat_map(|row| row.iter().filter(|i| !draws.contains(i)))
+                    .sum(),
+            );
+        }
+    }
+    None
+}
+
+pub type Parsed = (Vec<usize>, Vec<Vec<Vec<usize>>>);
+
+/// The rows of a 5x5 board followed by its columns.
+fn parse_board(section: &[Line]) -> Result<Vec<Vec<usize>>, ParseError> {
+    let mut rows = Vec::new();
+    for line in section {
+        let row = line
+            .text
+            .split_whitespace()
+            .map(|n| line.num(n))
+            .collect::<Result<Vec<_>, _>>()?;
+        if row.len() != 5 {
+            return Err(line.error_at_end("5 numbers"));
+        }
+        rows.push(row);
+    }
+    if rows.len() != 5 {
+        return Err(section[section.len() - 1].error_at_end("5 rows"));
+    }
+
+    let mut board: Vec<Vec<usize>> = rows.clone();
+    for i in 0..rows[0].len() {
+        board.push(rows.iter().map(|row| row[i]).collect());
+    }
+
+    Ok(board)
+}
+
+pub fn four_impl(input: &Parsed, wait_last: bool) -> usize {
+    let (draws, boards) = input;
+    let mut current_draws = HashSet::new();
+    let mut winning_boards = HashSet::new();
+
+    for &draw in draws {
+        current_draws.insert(draw);
+
+        let winner: Vec<(usize, usize)> = boards
+            .iter()
+            .enumerate()
+            .filter(|(i, _b)| !wait_last || !winning_boards.contains(i))
+            .filter_map(|(i, b)| get_victory_row(b, &current_draws).map(|w| (i, w)))
+            .collect();
+
+        for w in winner {
+            if !wait_last || winning_boards.len() == boards.len() - 1 {
+                return w.1 * draw;
+            }
+            winning_boards.insert(w.0);
+        }
+    }
+
+    unimplemented!()
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    let sections = sections(input);
+    let draws = section(input, &sections, 0, "the draws")?;
+    let draws = draws[0].ints(',')?;
+    let boards = sections[1..]
+        .iter()
+        .map(|board| parse_board(board))
+        .collect::<Result<_, _>>()?;
+    Ok((draws, boards))
+}
+
+pub struct Four;
+
+impl Solution for Four {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        four_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        four_impl(input, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day_4::parse;
+
+    #[test]
+    fn parse_errors() {
+        let lines = vec![
+            "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1",
+            "",
+            "22 13 17 11  0",
+            " 8  2 23  4 24",
+            "21  9 14 16  7",
+            " 6 10  3 18  5",
+            " 1 12 20 15 19",
+            "",
+            " 3 15  0  2 22",
+            " 9 18 13 17  5",
+            "19  8  7 25 23",
+            "20 11 10 24  4",
+            "14 21 16 12  6",
+            "",
+            "14 21 17 24  4",
+            "10 16 15  9 19",
+            "18  8 23 26 20",
+            "22 11 13  6  5",
+            " 2  0 12  3  7",
+        ];
+        let err = parse(&lines[..5]).unwrap_err();
+        assert_eq!(
+            (5, 15, "5 rows"),
+            (err.line, err.column, err.expected.as_str())
+        );
+        let mut lines = lines;
+        lines[4] = "21  9 14 16";
+        let err = parse(&lines).unwrap_err();
+        assert_eq!(
+            (5, 12, "5 numbers"),
+            (err.line, err.column, err.expected.as_str())
+        );
+    }
+}
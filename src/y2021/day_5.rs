@@ -0,0 +1,89 @@
+use crate::answer::Answer;
+use crate::parsing::{self, ParseError};
+use crate::point::Point2;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line {
+    pub start: Point2,
+    pub end: Point2,
+}
+
+impl Line {
+    fn part_1_line(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    /// The points on the line, which is horizontal, vertical or diagonal.
+    fn points(&self) -> HashSet<Point2> {
+        let step = (self.end - self.start).signum();
+        let len = self.start.chebyshev(self.end) as i32;
+        (0..=len).map(|i| self.start + step * i).collect()
+    }
+}
+
+fn get_coll(a: Line, b: Line) -> Vec<Point2> {
+    a.points().intersection(&b.points()).copied().collect()
+}
+
+pub fn five_impl(input: &[Line], part1: bool) -> usize {
+    let mut points: HashSet<Point2> = HashSet::new();
+
+    for a in 0..input.len() {
+        for b in 0..input.len() {
+            if a >= b {
+                continue;
+            }
+
+            if part1 && (!input[a].part_1_line() || !input[b].part_1_line()) {
+                continue;
+            }
+
+            for coll in get_coll(input[a], input[b]) {
+                if coll == Point2::new(1, 3) {
+                    panic!()
+                }
+                points.insert(coll);
+            }
+        }
+    }
+
+    points.len()
+}
+
+fn parse_point(line: &parsing::Line, point: &str) -> Result<Point2, ParseError> {
+    let mut coords = point.split(',');
+    let x = line.num(line.field(coords.next(), "x")?)?;
+    let y = line.num(line.field(coords.next(), "`,` and y")?)?;
+    Ok(Point2::new(x, y))
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<Line>, ParseError> {
+    parsing::lines(input)
+        .map(|line| {
+            let mut points = line.text.split(" -> ");
+            let start = parse_point(&line, line.field(points.next(), "a point")?)?;
+            let end = parse_point(&line, line.field(points.next(), "` -> ` and a point")?)?;
+            Ok(Line { start, end })
+        })
+        .collect()
+}
+
+pub struct Five;
+
+impl Solution for Five {
+    type Parsed = Vec<Line>;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<Line>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Line>) -> Answer {
+        five_impl(input, true).into()
+    }
+
+    fn part2(input: &Vec<Line>) -> Answer {
+        five_impl(input, false).into()
+    }
+}
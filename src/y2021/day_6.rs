@@ -0,0 +1,51 @@
+use crate::answer::Answer;
+use crate::parsing::{comma_ints, ParseError};
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub fn six_impl(input: &HashMap<i32, usize>, days: i32) -> usize {
+    let mut fish = input.clone();
+
+    for _ in 0..days {
+        let mut new_input = HashMap::new();
+
+        for (k, v) in fish {
+            if k == 0 {
+                new_input.entry(8).and_modify(|e| *e += v).or_insert(v);
+                new_input.entry(6).and_modify(|e| *e += v).or_insert(v);
+            } else {
+                new_input.entry(k - 1).and_modify(|e| *e += v).or_insert(v);
+            }
+        }
+
+        fish = new_input;
+    }
+
+    fish.values().copied().sum()
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<HashMap<i32, usize>, ParseError> {
+    let mut map = HashMap::new();
+    for num in comma_ints(input)? {
+        map.entry(num).and_modify(|e| *e += 1).or_insert(1);
+    }
+    Ok(map)
+}
+
+pub struct Six;
+
+impl Solution for Six {
+    type Parsed = HashMap<i32, usize>;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<HashMap<i32, usize>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &HashMap<i32, usize>) -> Answer {
+        six_impl(input, 80).into()
+    }
+
+    fn part2(input: &HashMap<i32, usize>) -> Answer {
+        six_impl(input, 256).into()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::answer::Answer;
+use crate::parsing::{comma_ints, ParseError};
+use crate::solution::Solution;
+
+fn seven_cost(input: &[i32], pos: i32) -> usize {
+    input
+        .iter()
+        .map(|x| (*x - pos).unsigned_abs() as usize)
+        .sum()
+}
+
+fn seven_exp_cost(input: &[i32], pos: i32) -> usize {
+    input
+        .iter()
+        .map(|x| {
+            let n = (*x - pos).unsigned_abs() as usize;
+            (n * (n + 1)) / 2
+        })
+        .sum()
+}
+
+pub fn seven_impl(input: &[i32], exp: bool) -> usize {
+    let min_pos = *input.iter().min().unwrap();
+    let max_pos = *input.iter().max().unwrap();
+
+    if exp {
+        (min_pos..=max_pos)
+            .map(|pos| seven_exp_cost(input, pos))
+            .min()
+            .unwrap()
+    } else {
+        (min_pos..=max_pos)
+            .map(|pos| seven_cost(input, pos))
+            .min()
+            .unwrap()
+    }
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<i32>, ParseError> {
+    let crabs = comma_ints(input)?;
+    if crabs.is_empty() {
+        return Err(ParseError::missing(input, "the crab positions"));
+    }
+    Ok(crabs)
+}
+
+pub struct Seven;
+
+impl Solution for Seven {
+    type Parsed = Vec<i32>;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Vec<i32>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<i32>) -> Answer {
+        seven_impl(input, false).into()
+    }
+
+    fn part2(input: &Vec<i32>) -> Answer {
+        seven_impl(input, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day_7::{parse, seven_cost};
+
+    #[test]
+    fn costs() {
+        let lines = vec!["16,1,2,0,4,2,7,1,2,14"];
+        assert_eq!(37, seven_cost(&parse(&lines).unwrap(), 2));
+        assert_eq!(41, seven_cost(&parse(&lines).unwrap(), 1));
+        assert_eq!(39, seven_cost(&parse(&lines).unwrap(), 3));
+        assert_eq!(71, seven_cost(&parse(&lines).unwrap(), 10));
+        assert_eq!(1, parse::<&str>(&[]).unwrap_err().line);
+    }
+}
@@ -1,13 +1,15 @@
+use crate::answer::Answer;
+use crate::parsing::{lines, Line, ParseError};
+use crate::solution::Solution;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 const ONE_BITS: u32 = 2;
 const FOUR_BITS: u32 = 4;
 const SEVEN_BITS: u32 = 3;
 const EIGHT_BITS: u32 = 7;
 
+pub type Parsed = Vec<(Vec<u8>, Vec<u8>)>;
+
 fn calc_map(numbers: &[u8]) -> HashMap<u8, u8> {
     let mut segments: HashMap<u8, HashSet<u8>> = HashMap::new();
     segments.insert(0, [0, 2, 3, 5, 6, 7, 8, 9].iter().copied().collect());
@@ -84,7 +86,7 @@ fn calc_map(numbers: &[u8]) -> HashMap<u8, u8> {
     reversed_translate_map
 }
 
-fn eight_impl(input: &[(Vec<u8>, Vec<u8>)], day_2: bool) -> usize {
+pub fn eight_impl(input: &[(Vec<u8>, Vec<u8>)], day_2: bool) -> usize {
     if !day_2 {
         return input
             .iter()
@@ -114,55 +116,46 @@ fn eight_impl(input: &[(Vec<u8>, Vec<u8>)], day_2: bool) -> usize {
     res
 }
 
-fn parse_num<S: AsRef<str>>(s: S) -> u8 {
-    s.as_ref().chars().map(|c| 1 << ((c as u8) - b'a')).sum()
+fn parse_num(line: &Line, s: &str) -> Result<u8, ParseError> {
+    let mut res = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            'a'..='g' => res |= 1 << ((c as u8) - b'a'),
+            _ => return Err(line.error(&s[i..], "a segment from a to g")),
+        }
+    }
+    Ok(res)
+}
+
+fn parse_nums(line: &Line, s: &str) -> Result<Vec<u8>, ParseError> {
+    s.split(' ').map(|s| parse_num(line, s)).collect()
 }
 
-fn parse<S: AsRef<str>>(input: &[S]) -> Vec<(Vec<u8>, Vec<u8>)> {
-    let i = input
-        .iter()
-        .map(|s| s.as_ref().split(" | ").collect())
-        .collect::<Vec<Vec<_>>>();
-    i.iter()
-        .map(|row| {
-            (
-                row[0].split(' ').map(parse_num).collect(),
-                row[1].split(' ').map(parse_num).collect(),
-            )
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    lines(input)
+        .map(|line| {
+            let mut halves = line.text.split(" | ");
+            let patterns = parse_nums(&line, line.field(halves.next(), "patterns")?)?;
+            let output = parse_nums(&line, line.field(halves.next(), "` | ` and the output")?)?;
+            Ok((patterns, output))
         })
         .collect()
 }
 
-pub fn eight() -> Result<(), std::io::Error> {
-    let file = File::open("8_input")?;
-    let reader = BufReader::new(file);
-    let lines = parse(&reader.lines().map(|s| s.unwrap()).collect::<Vec<_>>());
-    let res = eight_impl(&lines, false);
-    println!("Day 8 part 1: {}", res);
-    let res_2 = eight_impl(&lines, true);
-    println!("Day 8 part 2: {}", res_2);
-    Ok(())
-}
+pub struct Eight;
+
+impl Solution for Eight {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        eight_impl(input, false).into()
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::day_8::{eight_impl, parse};
-
-    #[test]
-    fn it_works() {
-        let lines = vec![
-"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe",
-"edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc",
-"fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg",
-"fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb",
-"aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea",
-"fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb",
-"dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe",
-"bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef",
-"egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb",
-"gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
-        ];
-        assert_eq!(26, eight_impl(&parse(&lines), false));
-        assert_eq!(61229, eight_impl(&parse(&lines), true));
+    fn part2(input: &Parsed) -> Answer {
+        eight_impl(input, true).into()
     }
 }
@@ -1,9 +1,10 @@
+use crate::answer::Answer;
 use crate::grid::Grid;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
+use crate::parsing::{digit_grid, ParseError};
+use crate::point::Point2;
+use crate::solution::Solution;
 
-type Parsed = Grid<i32>;
+pub type Parsed = Grid<i32>;
 
 fn get_lowest_nbor(
     visited: &mut Grid<(bool, Option<i32>)>,
@@ -18,8 +19,8 @@ fn get_lowest_nbor(
     } else {
         visited.set(x, y, (true, None));
         let mut nbors = Vec::new();
-        for (nx, ny) in grid.nbors(x, y) {
-            if *grid.get(nx, ny).unwrap() <= this {
+        for (nx, ny, &nbor) in grid.nbor_cells(x, y, &Point2::ORTHOGONAL) {
+            if nbor <= this {
                 nbors.push(get_lowest_nbor(visited, grid, nx, ny));
             }
         }
@@ -48,18 +49,18 @@ fn basin_size(visited: &mut Grid<bool>, grid: &Parsed, x: usize, y: usize) -> Op
     Some(size)
 }
 
-fn nine_impl(input: &Parsed, day_2: bool) -> i32 {
+pub fn nine_impl(input: &Parsed, day_2: bool) -> i32 {
     if !day_2 {
-        let mut visited = Grid::new_with(input.row_size(), input.col_size(), |_, _| (false, None));
-        for y in 0..input.col_size() {
-            for x in 0..input.row_size() {
+        let mut visited = Grid::new_with(input.width(), input.height(), |_, _| (false, None));
+        for y in 0..input.height() {
+            for x in 0..input.width() {
                 get_lowest_nbor(&mut visited, input, x, y);
             }
         }
 
         let mut risks = 0;
-        for y in 0..input.col_size() {
-            for x in 0..input.row_size() {
+        for y in 0..input.height() {
+            for x in 0..input.width() {
                 if visited.get(x, y).unwrap().1.unwrap() == *input.get(x, y).unwrap() {
                     risks += 1 + input.get(x, y).unwrap();
                 }
@@ -68,10 +69,10 @@ fn nine_impl(input: &Parsed, day_2: bool) -> i32 {
         return risks;
     }
 
-    let mut visited = Grid::new_with(input.row_size(), input.col_size(), |_, _| false);
+    let mut visited = Grid::new_with(input.width(), input.height(), |_, _| false);
     let mut basins = Vec::new();
-    for y in 0..input.col_size() {
-        for x in 0..input.row_size() {
+    for y in 0..input.height() {
+        for x in 0..input.width() {
             if let Some(b) = basin_size(&mut visited, input, x, y) {
                 basins.push(b as i32);
             }
@@ -82,45 +83,24 @@ fn nine_impl(input: &Parsed, day_2: bool) -> i32 {
     basins.into_iter().take(3).product()
 }
 
-fn parse<S: AsRef<str>>(input: &[S]) -> Parsed {
-    Grid::new(
-        input
-            .iter()
-            .map(|s| {
-                s.as_ref()
-                    .chars()
-                    .map(|c| c.to_digit(10).unwrap() as i32)
-                    .collect()
-            })
-            .collect::<Vec<Vec<_>>>(),
-    )
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    digit_grid(input)
 }
 
-pub fn nine() -> Result<(), std::io::Error> {
-    let file = File::open("9_input")?;
-    let reader = BufReader::new(file);
-    let lines = parse(&reader.lines().map(|s| s.unwrap()).collect::<Vec<_>>());
-    let res = nine_impl(&lines, false);
-    println!("Day 9 part 1: {}", res);
-    let res_2 = nine_impl(&lines, true);
-    println!("Day 9 part 2: {}", res_2);
-    Ok(())
-}
+pub struct Nine;
+
+impl Solution for Nine {
+    type Parsed = Parsed;
 
-#[cfg(test)]
-mod tests {
-    use crate::day_9::{nine_impl, parse};
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        nine_impl(input, false).into()
+    }
 
-    #[test]
-    fn it_works() {
-        let lines = vec![
-            "2199943210",
-            "3987894921",
-            "9856789892",
-            "8767896789",
-            "9899965678",
-        ];
-        assert_eq!(15, nine_impl(&parse(&lines), false));
-        assert_eq!(1134, nine_impl(&parse(&lines), true));
+    fn part2(input: &Parsed) -> Answer {
+        nine_impl(input, true).into()
     }
 }
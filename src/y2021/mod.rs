@@ -0,0 +1,191 @@
+//! Solutions for 2021.
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_24b;
+pub mod day_25;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+use crate::solution::Day;
+
+/// Every solver, in run order. The first entry for a day is the one that
+/// ranges and `all` run.
+pub const DAYS: &[Day] = &[
+    Day {
+        year: 2021,
+        id: "1",
+        number: 1,
+        solver: &day_1::One,
+    },
+    Day {
+        year: 2021,
+        id: "2",
+        number: 2,
+        solver: &day_2::Two,
+    },
+    Day {
+        year: 2021,
+        id: "3",
+        number: 3,
+        solver: &day_3::Three,
+    },
+    Day {
+        year: 2021,
+        id: "4",
+        number: 4,
+        solver: &day_4::Four,
+    },
+    Day {
+        year: 2021,
+        id: "5",
+        number: 5,
+        solver: &day_5::Five,
+    },
+    Day {
+        year: 2021,
+        id: "6",
+        number: 6,
+        solver: &day_6::Six,
+    },
+    Day {
+        year: 2021,
+        id: "7",
+        number: 7,
+        solver: &day_7::Seven,
+    },
+    Day {
+        year: 2021,
+        id: "8",
+        number: 8,
+        solver: &day_8::Eight,
+    },
+    Day {
+        year: 2021,
+        id: "9",
+        number: 9,
+        solver: &day_9::Nine,
+    },
+    Day {
+        year: 2021,
+        id: "10",
+        number: 10,
+        solver: &day_10::Ten,
+    },
+    Day {
+        year: 2021,
+        id: "11",
+        number: 11,
+        solver: &day_11::Eleven,
+    },
+    Day {
+        year: 2021,
+        id: "12",
+        number: 12,
+        solver: &day_12::Twelve,
+    },
+    Day {
+        year: 2021,
+        id: "13",
+        number: 13,
+        solver: &day_13::Thirteen,
+    },
+    Day {
+        year: 2021,
+        id: "14",
+        number: 14,
+        solver: &day_14::Fourteen,
+    },
+    Day {
+        year: 2021,
+        id: "15",
+        number: 15,
+        solver: &day_15::Fifteen,
+    },
+    Day {
+        year: 2021,
+        id: "16",
+        number: 16,
+        solver: &day_16::Sixteen,
+    },
+    Day {
+        year: 2021,
+        id: "17",
+        number: 17,
+        solver: &day_17::Seventeen,
+    },
+    Day {
+        year: 2021,
+        id: "18",
+        number: 18,
+        solver: &day_18::Eighteen,
+    },
+    Day {
+        year: 2021,
+        id: "19",
+        number: 19,
+        solver: &day_19::Nineteen,
+    },
+    Day {
+        year: 2021,
+        id: "20",
+        number: 20,
+        solver: &day_20::Twenty,
+    },
+    Day {
+        year: 2021,
+        id: "21",
+        number: 21,
+        solver: &day_21::TwentyOne,
+    },
+    Day {
+        year: 2021,
+        id: "22",
+        number: 22,
+        solver: &day_22::TwentyTwo,
+    },
+    Day {
+        year: 2021,
+        id: "23",
+        number: 23,
+        solver: &day_23::TwentyThree,
+    },
+    Day {
+        year: 2021,
+        id: "24b",
+        number: 24,
+        solver: &day_24b::TwentyFour,
+    },
+    Day {
+        year: 2021,
+        id: "24",
+        number: 24,
+        solver: &day_24::TwentyFour,
+    },
+    Day {
+        year: 2021,
+        id: "25",
+        number: 25,
+        solver: &day_25::TwentyFive,
+    },
+];
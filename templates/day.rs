@@ -0,0 +1,41 @@
+use crate::answer::Answer;
+use crate::parsing::{lines, ParseError};
+use crate::solution::Solution;
+
+pub type Parsed = Vec<String>;
+
+pub fn {{name}}_impl(input: &Parsed, day_2: bool) -> usize {
+    unimplemented!("{} lines, part 2: {}", input.len(), day_2)
+}
+
+pub fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+    Ok(lines(input).map(|line| line.text.to_string()).collect())
+}
+
+pub struct {{Name}};
+
+impl Solution for {{Name}} {
+    type Parsed = Parsed;
+
+    fn parse<S: AsRef<str>>(input: &[S]) -> Result<Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Parsed) -> Answer {
+        {{name}}_impl(input, false).into()
+    }
+
+    fn part2(input: &Parsed) -> Answer {
+        {{name}}_impl(input, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y{{year}}::day_{{id}}::parse;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(vec!["1", "2"], parse(&["1", "2"]).unwrap());
+    }
+}